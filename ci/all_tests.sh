@@ -105,6 +105,22 @@ MIGRATED_EXAMPLES=(
     "command"
     "time"
     "random"
+    "file-copy"
+    "dir-walk"
+    "temp-files"
+    "watch"
+    "locale"
+    "process"
+    "signal"
+    "bytes-stdin-stdout"
+    "non-utf8-path"
+    "links"
+    "file-metadata"
+    "file-move"
+    "file-mmap"
+    "file-lock"
+    "env-var"
+    "stdin-pipe"
 )
 
 EXAMPLES_DIR="${ROOT_DIR}/examples/"
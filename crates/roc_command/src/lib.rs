@@ -2,19 +2,23 @@
 
 use roc_io_error::IOErr;
 use roc_std_new::{RocList, RocOps, RocRefcounted, RocStr};
+use std::ffi::OsStr;
+use std::os::unix::ffi::OsStrExt;
 
 /// Command struct matching the Roc record memory layout.
 ///
 /// IMPORTANT: Roc optimizes struct layouts by putting larger fields first!
 /// The type signature is `{ args, clear_envs, envs, program }` (alphabetical),
 /// but the MEMORY layout is: args (24), envs (24), program (24), clear_envs (1).
+///
+/// Arguments are raw bytes (`List(List(U8))` in Roc) so paths that are not valid UTF-8 can be passed.
 #[derive(Clone, Debug)]
 #[repr(C)]
 pub struct Command {
-    pub args: RocList<RocStr>,    // offset 0 (24 bytes)
-    pub envs: RocList<RocStr>,    // offset 24 (24 bytes)
-    pub program: RocStr,          // offset 48 (24 bytes)
-    pub clear_envs: u8,           // offset 72 (1 byte + 7 padding = 80 total)
+    pub args: RocList<RocList<u8>>, // offset 0 (24 bytes)
+    pub envs: RocList<RocStr>,      // offset 24 (24 bytes)
+    pub program: RocStr,            // offset 48 (24 bytes)
+    pub clear_envs: u8,             // offset 72 (1 byte + 7 padding = 80 total)
 }
 
 impl RocRefcounted for Command {
@@ -40,7 +44,7 @@ impl Command {
 
         // Add arguments
         for arg in self.args.iter() {
-            cmd.arg(arg.as_str());
+            cmd.arg(OsStr::from_bytes(arg.as_slice()));
         }
 
         // Clear environment if requested
@@ -0,0 +1,852 @@
+//! This crate provides common functionality for Roc to traverse, copy and inspect directory trees with `std::fs`
+
+use roc_io_error::IOErr;
+use roc_std_new::{roc_refcounted_noop_impl, RocList, RocOps, RocRefcounted, RocStr};
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::mem::ManuallyDrop;
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::{MetadataExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+/// Options for `Dir.walk!`.
+/// Roc type: { follow_symlinks : Bool, max_depth : U64, skip_hidden : Bool, sort : Bool }
+/// Memory layout: Fields sorted by alignment, then alphabetically.
+/// U64 (8-byte aligned) > Bool (1-byte aligned), so: max_depth, follow_symlinks, skip_hidden, sort
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct WalkOptions {
+    pub max_depth: u64,        // offset 0 (8 bytes)
+    pub follow_symlinks: bool, // offset 8 (1 byte)
+    pub skip_hidden: bool,     // offset 9 (1 byte)
+    pub sort: bool,            // offset 10 (1 byte + padding = 16 total)
+}
+
+/// Roc type: [Dir, File, Other, Symlink]
+/// Tags without payloads are represented by their discriminant, in alphabetical order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EntryType {
+    Dir = 0,
+    File = 1,
+    Other = 2,
+    Symlink = 3,
+}
+
+impl From<fs::FileType> for EntryType {
+    fn from(file_type: fs::FileType) -> Self {
+        if file_type.is_dir() {
+            EntryType::Dir
+        } else if file_type.is_file() {
+            EntryType::File
+        } else if file_type.is_symlink() {
+            EntryType::Symlink
+        } else {
+            EntryType::Other
+        }
+    }
+}
+
+/// An entry found by [walk], before conversion to Roc types.
+#[derive(Debug)]
+pub struct WalkEntry {
+    pub path: PathBuf,
+    pub depth: u64,
+    pub entry_type: EntryType,
+    /// Why the entry's type could not be read (it is then `Other`),
+    /// or why a directory's contents could not be listed.
+    ///
+    /// If reading a directory fails partway through, the entries read so far are kept and
+    /// the error is an extra `Other` entry with the path of the directory itself.
+    pub error: Option<io::Error>,
+    /// Metadata of a file, not following symlinks. Only read when the walk needs it,
+    /// like for [disk_usage].
+    pub metadata: Option<fs::Metadata>,
+}
+
+/// Roc type: [Dir, File, Other, Symlink, Unreadable(IOErr)]
+/// Memory layout: the IOErr payload, then the discriminant, tags in alphabetical order.
+#[repr(C)]
+pub struct RocEntryType {
+    payload: ManuallyDrop<IOErr>,
+    discriminant: u8, // Dir=0, File=1, Other=2, Symlink=3, Unreadable=4
+}
+
+const UNREADABLE: u8 = 4;
+
+impl RocEntryType {
+    fn new(entry_type: EntryType, error: Option<&io::Error>, roc_ops: &RocOps) -> Self {
+        match error {
+            Some(err) => Self {
+                payload: ManuallyDrop::new(IOErr::from_io_error(err, roc_ops)),
+                discriminant: UNREADABLE,
+            },
+            None => Self {
+                payload: ManuallyDrop::new(IOErr::not_found()),
+                discriminant: entry_type as u8,
+            },
+        }
+    }
+}
+
+impl RocRefcounted for RocEntryType {
+    fn inc(&mut self) {
+        if self.discriminant == UNREADABLE {
+            self.payload.inc();
+        }
+    }
+    fn dec(&mut self) {
+        if self.discriminant == UNREADABLE {
+            self.payload.dec();
+        }
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// Entry returned by `Dir.walk!`.
+/// Roc type: { depth : U64, name : Path, path : Path, type : [Dir, File, Other, Symlink, Unreadable(IOErr)] }
+/// Path is the record { bytes : List(U8) }, which has the same layout as a List(U8).
+/// Memory layout: Fields sorted by alignment, then alphabetically.
+/// All fields are 8-byte aligned, so: depth, name, path, type
+#[repr(C)]
+pub struct RocWalkEntry {
+    pub depth: u64,               // offset 0 (8 bytes)
+    pub name: RocList<u8>,        // offset 8 (24 bytes)
+    pub path: RocList<u8>,        // offset 32 (24 bytes)
+    pub entry_type: RocEntryType, // offset 56 (33 bytes + padding = 96 total)
+}
+
+impl RocRefcounted for RocWalkEntry {
+    fn inc(&mut self) {
+        self.name.inc();
+        self.path.inc();
+        self.entry_type.inc();
+    }
+    fn dec(&mut self) {
+        self.name.dec();
+        self.path.dec();
+        self.entry_type.dec();
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+impl WalkEntry {
+    /// Convert to the Roc record. Names and paths keep their bytes, even if they are not valid UTF-8.
+    pub fn to_roc(&self, roc_ops: &RocOps) -> RocWalkEntry {
+        let name = self.path.file_name().unwrap_or_default();
+
+        RocWalkEntry {
+            depth: self.depth,
+            name: bytes_to_roc_list(name.as_bytes(), roc_ops),
+            path: bytes_to_roc_list(self.path.as_os_str().as_bytes(), roc_ops),
+            entry_type: RocEntryType::new(self.entry_type, self.error.as_ref(), roc_ops),
+        }
+    }
+}
+
+/// Copy the bytes of a path into a new RocList<u8>, the host side of a Roc Path.
+fn bytes_to_roc_list(bytes: &[u8], roc_ops: &RocOps) -> RocList<u8> {
+    let mut list = RocList::with_capacity(bytes.len(), roc_ops);
+    for &byte in bytes {
+        list.push(byte, roc_ops);
+    }
+    list
+}
+
+/// Convert a path to a Roc Path, keeping its bytes even if they are not valid UTF-8.
+pub fn os_str_to_roc_path(os_str: &OsStr, roc_ops: &RocOps) -> RocList<u8> {
+    bytes_to_roc_list(os_str.as_bytes(), roc_ops)
+}
+
+/// Entry returned by `Dir.entries!`.
+/// Roc type: { name : Path, path : Path, type : [Dir, File, Other, Symlink, Unreadable(IOErr)] }
+/// Memory layout: Fields sorted by alignment, then alphabetically.
+/// All fields are 8-byte aligned, so: name, path, type
+#[repr(C)]
+pub struct RocDirEntry {
+    pub name: RocList<u8>,        // offset 0 (24 bytes)
+    pub path: RocList<u8>,        // offset 24 (24 bytes)
+    pub entry_type: RocEntryType, // offset 48 (33 bytes + padding = 88 total)
+}
+
+impl RocRefcounted for RocDirEntry {
+    fn inc(&mut self) {
+        self.name.inc();
+        self.path.inc();
+        self.entry_type.inc();
+    }
+    fn dec(&mut self) {
+        self.name.dec();
+        self.path.dec();
+        self.entry_type.dec();
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// Convert the immediate children of a directory to a Roc list, dropping the depth.
+pub fn dir_entries_to_roc(entries: &[WalkEntry], roc_ops: &RocOps) -> RocList<RocDirEntry> {
+    let mut list = RocList::with_capacity(entries.len(), roc_ops);
+    for entry in entries {
+        let RocWalkEntry {
+            name,
+            path,
+            entry_type,
+            ..
+        } = entry.to_roc(roc_ops);
+        list.push(
+            RocDirEntry {
+                name,
+                path,
+                entry_type,
+            },
+            roc_ops,
+        );
+    }
+    list
+}
+
+/// Convert walk results to a Roc list.
+pub fn walk_entries_to_roc(entries: &[WalkEntry], roc_ops: &RocOps) -> RocList<RocWalkEntry> {
+    let mut list = RocList::with_capacity(entries.len(), roc_ops);
+    for entry in entries {
+        list.push(entry.to_roc(roc_ops), roc_ops);
+    }
+    list
+}
+
+/// Recursively list everything below `root`, parents before their children.
+///
+/// Children of `root` have depth 1. `root` itself is not included. Only failing to list
+/// `root` is an error: an entry that can't be read or a directory that can't be listed
+/// carries its error in [WalkEntry::error], and the walk goes on with the next entry.
+pub fn walk(root: &Path, options: &WalkOptions) -> io::Result<Vec<WalkEntry>> {
+    walk_reading_metadata(root, options, false)
+}
+
+/// Like [walk], and with `read_metadata`, also read the metadata of every file while its
+/// directory is being listed. A file whose metadata can't be read carries that error.
+fn walk_reading_metadata(
+    root: &Path,
+    options: &WalkOptions,
+    read_metadata: bool,
+) -> io::Result<Vec<WalkEntry>> {
+    let mut entries = Vec::new();
+    let mut ancestors = Vec::new();
+    if options.follow_symlinks {
+        ancestors.push(file_id(&fs::metadata(root)?));
+    }
+    let children = read_children(root, options)?;
+    walk_dir(root, children, 1, options, read_metadata, &mut ancestors, &mut entries);
+    Ok(entries)
+}
+
+/// List the immediate children of `dir`, in the order the OS returns them.
+///
+/// Unlike filtering `fs::read_dir` with `entry.ok()`, an entry whose type can't be read
+/// is kept, with its error in [WalkEntry::error].
+pub fn entries(dir: &Path) -> io::Result<Vec<WalkEntry>> {
+    let options = WalkOptions {
+        max_depth: 1,
+        follow_symlinks: false,
+        skip_hidden: false,
+        sort: false,
+    };
+    walk(dir, &options)
+}
+
+/// Result of `Dir.disk_usage!`.
+/// Roc type: { bytes : U64, files : U64 }
+/// Memory layout: both are U64, so alphabetically: bytes, files
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(C)]
+pub struct DiskUsage {
+    pub bytes: u64, // offset 0 (8 bytes)
+    pub files: u64, // offset 8 (8 bytes)
+}
+
+roc_refcounted_noop_impl!(DiskUsage);
+
+/// Add up the sizes of all regular files below `path`, or the size of `path` if it is a file.
+///
+/// Symlinks are not followed and files with several hard links are only counted once.
+/// Sizes are the file lengths, not the blocks they take up on disk.
+///
+/// Files that are deleted during the walk are left out. Any other entry that can't be read,
+/// like a directory without read permission, is an error.
+pub fn disk_usage(path: &Path) -> io::Result<DiskUsage> {
+    let metadata = fs::symlink_metadata(path)?;
+    if !metadata.is_dir() {
+        return Ok(if metadata.is_file() {
+            DiskUsage {
+                bytes: metadata.len(),
+                files: 1,
+            }
+        } else {
+            DiskUsage::default()
+        });
+    }
+
+    let options = WalkOptions {
+        max_depth: u64::MAX,
+        follow_symlinks: false,
+        skip_hidden: false,
+        sort: false,
+    };
+    let mut usage = DiskUsage::default();
+    let mut linked = HashSet::new();
+    for entry in walk_reading_metadata(path, &options, true)? {
+        match entry.error {
+            // Deleted while the walk was going on, so it takes up no space anymore
+            Some(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            // Leaving out a directory that can't be listed would give a total that is too small
+            Some(err) => return Err(err),
+            None => {}
+        }
+        let Some(metadata) = entry.metadata else {
+            continue;
+        };
+        if metadata.nlink() > 1 && !linked.insert(file_id(&metadata)) {
+            continue;
+        }
+        usage.bytes += metadata.len();
+        usage.files += 1;
+    }
+    Ok(usage)
+}
+
+/// Result of `Dir.copy_all!`.
+/// Roc type: { copied : U64, skipped : U64 }
+/// Memory layout: both are U64, so alphabetically: copied, skipped
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(C)]
+pub struct CopyStats {
+    pub copied: u64,
+    pub skipped: u64,
+}
+
+roc_refcounted_noop_impl!(CopyStats);
+
+/// Recursively copy the directory `from` to `to`, keeping permissions and symlinks.
+///
+/// With `skip_unchanged`, files are only copied if the destination is missing, differs in size,
+/// or is older than the source. Other special files like sockets and FIFOs are skipped.
+pub fn copy_all(
+    from: &Path,
+    to: &Path,
+    skip_unchanged: bool,
+) -> io::Result<CopyStats> {
+    let options = WalkOptions {
+        max_depth: u64::MAX,
+        follow_symlinks: false,
+        skip_hidden: false,
+        sort: false,
+    };
+    let entries = walk(from, &options)?;
+
+    fs::create_dir_all(to)?;
+    make_owner_writable(to, &fs::metadata(to)?)?;
+    let mut dirs = vec![(to.to_path_buf(), fs::metadata(from)?.permissions())];
+    let mut stats = CopyStats {
+        copied: 0,
+        skipped: 0,
+    };
+
+    for entry in entries {
+        if let Some(err) = entry.error {
+            return Err(err);
+        }
+        let relative = entry.path.strip_prefix(from).unwrap_or(&entry.path);
+        let dest = to.join(relative);
+        let existing = fs::symlink_metadata(&dest).ok();
+
+        match entry.entry_type {
+            EntryType::Dir => {
+                match existing {
+                    Some(metadata) if metadata.is_dir() => make_owner_writable(&dest, &metadata)?,
+                    _ => fs::create_dir(&dest)?,
+                }
+                dirs.push((dest, fs::metadata(&entry.path)?.permissions()));
+            }
+            EntryType::File => {
+                let source = fs::metadata(&entry.path)?;
+                let unchanged = existing.as_ref().is_some_and(|metadata| {
+                    metadata.is_file()
+                        && metadata.len() == source.len()
+                        && (metadata.mtime(), metadata.mtime_nsec()) >= (source.mtime(), source.mtime_nsec())
+                });
+                if skip_unchanged && unchanged {
+                    stats.skipped += 1;
+                    continue;
+                }
+                // Replace instead of writing through a symlink or into a read-only file
+                if existing.is_some() {
+                    fs::remove_file(&dest)?;
+                }
+                copy_file(&entry.path, &dest, false)?;
+                stats.copied += 1;
+            }
+            EntryType::Symlink => {
+                let target = fs::read_link(&entry.path)?;
+                if existing.as_ref().is_some_and(|metadata| metadata.file_type().is_symlink())
+                    && fs::read_link(&dest)? == target
+                {
+                    stats.skipped += 1;
+                    continue;
+                }
+                if existing.is_some() {
+                    fs::remove_file(&dest)?;
+                }
+                std::os::unix::fs::symlink(&target, &dest)?;
+                stats.copied += 1;
+            }
+            EntryType::Other => stats.skipped += 1,
+        }
+    }
+
+    // Deepest first, so read-only directories are filled before they are locked down.
+    // This also restores existing directories that were made writable above.
+    for (dir, permissions) in dirs.into_iter().rev() {
+        fs::set_permissions(dir, permissions)?;
+    }
+
+    Ok(stats)
+}
+
+/// Let the owner write into an existing directory, so a previous copy of a read-only directory can be updated.
+fn make_owner_writable(dir: &Path, metadata: &fs::Metadata) -> io::Result<()> {
+    let mode = metadata.permissions().mode();
+    if mode & 0o700 == 0o700 {
+        return Ok(());
+    }
+    fs::set_permissions(dir, fs::Permissions::from_mode(mode | 0o700))
+}
+
+/// Copy a file's contents and permission bits, optionally preserving its owner and timestamps.
+///
+/// On Linux this first tries to clone the file (reflink), which is instant on copy-on-write
+/// filesystems like btrfs and xfs. Otherwise it falls back to `fs::copy`, which uses
+/// `copy_file_range` on Linux and `fclonefileat`/`fcopyfile` on macOS.
+pub fn copy_file(from: &Path, to: &Path, preserve_metadata: bool) -> io::Result<()> {
+    if !try_reflink(from, to)? {
+        fs::copy(from, to)?;
+    }
+    if preserve_metadata {
+        copy_metadata(from, to)?;
+    }
+    Ok(())
+}
+
+/// Try to clone `from` into `to` with the FICLONE ioctl.
+///
+/// Returns `Ok(false)` if the filesystem does not support cloning.
+#[cfg(target_os = "linux")]
+fn try_reflink(from: &Path, to: &Path) -> io::Result<bool> {
+    use std::os::fd::AsRawFd;
+
+    let src = fs::File::open(from)?;
+    let metadata = src.metadata()?;
+    if !metadata.is_file() {
+        return Ok(false);
+    }
+    // An existing destination is only changed once the clone succeeded
+    let (dst, created) = match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(to)
+    {
+        Ok(file) => (file, true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            (fs::OpenOptions::new().write(true).open(to)?, false)
+        }
+        Err(e) => return Err(e),
+    };
+    let ret = unsafe { libc::ioctl(dst.as_raw_fd(), libc::FICLONE as _, src.as_raw_fd()) };
+    if ret == -1 {
+        // fs::copy will truncate and rewrite the destination
+        if created {
+            let _ = fs::remove_file(to);
+        }
+        return Ok(false);
+    }
+    // FICLONE does not shrink a destination that was longer than the source
+    dst.set_len(metadata.len())?;
+    dst.set_permissions(metadata.permissions())?;
+    Ok(true)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn try_reflink(_from: &Path, _to: &Path) -> io::Result<bool> {
+    Ok(false)
+}
+
+/// Copy owner, group, permissions and access/modification times from `from` to `to`.
+///
+/// Changing ownership needs elevated privileges, so failing to do so is not an error.
+fn copy_metadata(from: &Path, to: &Path) -> io::Result<()> {
+    let metadata = fs::metadata(from)?;
+    let _ = std::os::unix::fs::chown(to, Some(metadata.uid()), Some(metadata.gid()));
+    // Permissions go after chown, which may clear the setuid and setgid bits
+    fs::set_permissions(to, metadata.permissions())?;
+    set_file_times(
+        to,
+        libc::timespec {
+            tv_sec: metadata.atime() as libc::time_t,
+            tv_nsec: metadata.atime_nsec() as _,
+        },
+        libc::timespec {
+            tv_sec: metadata.mtime() as libc::time_t,
+            tv_nsec: metadata.mtime_nsec() as _,
+        },
+    )
+}
+
+/// Set the last accessed and last modified times of the file or directory at `path`.
+///
+/// Uses utimensat on the path instead of opening the file, so a file the owner
+/// can write but not read (like mode 0o200) works too.
+pub fn set_file_times(
+    path: &Path,
+    accessed: libc::timespec,
+    modified: libc::timespec,
+) -> io::Result<()> {
+    let c_path = std::ffi::CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let times = [accessed, modified];
+    if unsafe { libc::utimensat(libc::AT_FDCWD, c_path.as_ptr(), times.as_ptr(), 0) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Result of `Path.filesystem_stats!`.
+/// Roc type: { available_bytes : U64, free_bytes : U64, total_bytes : U64, type : Str }
+/// Memory layout: all fields are 8-byte aligned, so alphabetically
+#[repr(C)]
+pub struct FilesystemStats {
+    pub available_bytes: u64, // offset 0 (8 bytes)
+    pub free_bytes: u64,      // offset 8 (8 bytes)
+    pub total_bytes: u64,     // offset 16 (8 bytes)
+    pub fs_type: RocStr,      // offset 24 (24 bytes), `type` in Roc
+}
+
+impl RocRefcounted for FilesystemStats {
+    fn inc(&mut self) {
+        self.fs_type.inc();
+    }
+    fn dec(&mut self) {
+        self.fs_type.dec();
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// Size, free space and type of the filesystem containing `path`.
+///
+/// Free space includes blocks reserved for root, available space is what an
+/// unprivileged user can use.
+#[allow(clippy::useless_conversion)]
+pub fn filesystem_stats(path: &Path, roc_ops: &RocOps) -> io::Result<FilesystemStats> {
+    let c_path = std::ffi::CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stats) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // The field types differ between platforms, u64::from accepts all of them
+    let block_size = u64::from(stats.f_frsize);
+
+    Ok(FilesystemStats {
+        available_bytes: u64::from(stats.f_bavail) * block_size,
+        free_bytes: u64::from(stats.f_bfree) * block_size,
+        total_bytes: u64::from(stats.f_blocks) * block_size,
+        fs_type: RocStr::from_str(&filesystem_type(&c_path)?, roc_ops),
+    })
+}
+
+/// Name of the filesystem type, like `ext4` or `apfs`.
+#[cfg(target_os = "macos")]
+fn filesystem_type(c_path: &std::ffi::CStr) -> io::Result<String> {
+    let mut stats: libc::statfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statfs(c_path.as_ptr(), &mut stats) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let name = unsafe { std::ffi::CStr::from_ptr(stats.f_fstypename.as_ptr()) };
+    Ok(name.to_string_lossy().into_owned())
+}
+
+/// Name of the filesystem type, like `ext4` or `tmpfs`.
+///
+/// Linux only reports a magic number, so common ones are mapped to their names and
+/// others are returned in hex. ext2, ext3 and ext4 share a magic number and are all `ext4`.
+#[cfg(not(target_os = "macos"))]
+fn filesystem_type(c_path: &std::ffi::CStr) -> io::Result<String> {
+    let mut stats: libc::statfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statfs(c_path.as_ptr(), &mut stats) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // f_type is signed on some targets, the magic numbers are 32 bits
+    let magic = stats.f_type as u32;
+    let name = match magic {
+        0xEF53 => "ext4",
+        0x9123_683E => "btrfs",
+        0x5846_5342 => "xfs",
+        0x2FC1_2FC1 => "zfs",
+        0xF2F5_2010 => "f2fs",
+        0x0102_1994 => "tmpfs",
+        0x794C_7630 => "overlay",
+        0x6969 => "nfs",
+        0xFF53_4D42 => "cifs",
+        0xFE53_4D42 => "smb2",
+        0x6573_5546 => "fuse",
+        0x4D44 => "vfat",
+        0x2011_BAB0 => "exfat",
+        0x5346_544E => "ntfs",
+        0x9660 => "iso9660",
+        0x7371_7368 => "squashfs",
+        0x9FA0 => "proc",
+        0x6265_6572 => "sysfs",
+        0x8584_58F6 => "ramfs",
+        0x1CD1 => "devpts",
+        0x6367_7270 => "cgroup2",
+        _ => return Ok(format!("0x{magic:x}")),
+    };
+    Ok(name.to_string())
+}
+
+/// Like [walk], but skips paths ignored by `.gitignore`, `.ignore` and `.git/info/exclude`
+/// files, the way ripgrep does. Ignore files in parent directories of `root` apply too.
+///
+/// Unlike ripgrep, `.gitignore` files are respected even outside a git repository.
+/// Like git, the global gitignore (`core.excludesFile`, or `~/.config/git/ignore`) applies too.
+///
+/// Errors are handled like [walk]: links back to an enclosing directory are listed but not
+/// followed, and a directory that can't be listed carries its error in [WalkEntry::error].
+pub fn walk_respecting_ignores(root: &Path, options: &WalkOptions) -> io::Result<Vec<WalkEntry>> {
+    let mut builder = ignore::WalkBuilder::new(root);
+    builder
+        .hidden(options.skip_hidden)
+        .follow_links(options.follow_symlinks)
+        .max_depth(usize::try_from(options.max_depth).ok())
+        .require_git(false);
+    if options.sort {
+        builder.sort_by_file_name(|a, b| a.cmp(b));
+    }
+
+    let mut entries: Vec<WalkEntry> = Vec::new();
+    for result in builder.build() {
+        let entry = match result {
+            Ok(entry) => entry,
+            Err(err) => {
+                let (depth, path, inner) = unwrap_ignore_error(&err);
+                match (inner, depth) {
+                    // Like walk, list a link back to an enclosing directory but don't follow it again
+                    (ignore::Error::Loop { child, .. }, Some(depth)) => {
+                        if depth > 0 && !(options.skip_hidden && is_hidden(child)) {
+                            entries.push(WalkEntry {
+                                path: child.clone(),
+                                depth: depth as u64,
+                                entry_type: EntryType::Dir,
+                                error: None,
+                                metadata: None,
+                            });
+                        }
+                    }
+                    // Like walk, a directory that can't be listed keeps the error in its entry
+                    _ => match path.and_then(|path| entries.iter_mut().rev().find(|entry| entry.path == path)) {
+                        Some(entry) => entry.error = Some(ignore_error_to_io(err)),
+                        None => return Err(ignore_error_to_io(err)),
+                    },
+                }
+                continue;
+            }
+        };
+        // Depth 0 is `root` itself
+        if entry.depth() == 0 {
+            continue;
+        }
+        entries.push(WalkEntry {
+            depth: entry.depth() as u64,
+            entry_type: entry
+                .file_type()
+                .map_or(EntryType::Other, EntryType::from),
+            path: entry.into_path(),
+            error: None,
+            metadata: None,
+        });
+    }
+    Ok(entries)
+}
+
+/// Take off the depth and path that the ignore crate wraps around walk errors.
+fn unwrap_ignore_error(err: &ignore::Error) -> (Option<usize>, Option<&Path>, &ignore::Error) {
+    match err {
+        ignore::Error::WithDepth { depth, err } => {
+            let (_, path, inner) = unwrap_ignore_error(err);
+            (Some(*depth), path, inner)
+        }
+        ignore::Error::WithPath { path, err } => {
+            let (depth, _, inner) = unwrap_ignore_error(err);
+            (depth, Some(path), inner)
+        }
+        _ => (None, None, err),
+    }
+}
+
+fn ignore_error_to_io(err: ignore::Error) -> io::Error {
+    match err.io_error() {
+        // Keep the kind, so it maps to NotFound, PermissionDenied, ... in Roc
+        Some(io_err) => io::Error::new(io_err.kind(), err.to_string()),
+        None => io::Error::other(err.to_string()),
+    }
+}
+
+/// Identifies a file by device and inode, so symlink loops and hard links can be detected.
+fn file_id(metadata: &fs::Metadata) -> (u64, u64) {
+    (metadata.dev(), metadata.ino())
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.as_bytes().starts_with(b"."))
+}
+
+/// The entries of `dir`, sorted by name if requested.
+///
+/// Only opening `dir` is an error. An error while reading it is kept in the list, after
+/// the entries read before it.
+fn read_children(
+    dir: &Path,
+    options: &WalkOptions,
+) -> io::Result<Vec<io::Result<fs::DirEntry>>> {
+    let mut children = fs::read_dir(dir)?.collect::<Vec<_>>();
+    if options.sort {
+        children.sort_by_key(|child| match child {
+            Ok(child) => (false, child.file_name()),
+            Err(_) => (true, Default::default()),
+        });
+    }
+    Ok(children)
+}
+
+fn walk_dir(
+    dir: &Path,
+    children: Vec<io::Result<fs::DirEntry>>,
+    depth: u64,
+    options: &WalkOptions,
+    read_metadata: bool,
+    ancestors: &mut Vec<(u64, u64)>,
+    entries: &mut Vec<WalkEntry>,
+) {
+    if depth > options.max_depth {
+        return;
+    }
+
+    for child in children {
+        let child = match child {
+            Ok(child) => child,
+            // The name of the entry that failed is unknown, so report it as the directory
+            Err(err) => {
+                entries.push(WalkEntry {
+                    path: dir.to_path_buf(),
+                    depth,
+                    entry_type: EntryType::Other,
+                    error: Some(err),
+                    metadata: None,
+                });
+                continue;
+            }
+        };
+        let path = child.path();
+        if options.skip_hidden && is_hidden(&path) {
+            continue;
+        }
+
+        // DirEntry::file_type does not follow symlinks. Broken links stay Symlink.
+        let mut file_type = match child.file_type() {
+            Ok(file_type) => file_type,
+            // The entry may have been deleted since the directory was read
+            Err(err) => {
+                entries.push(WalkEntry {
+                    path,
+                    depth,
+                    entry_type: EntryType::Other,
+                    error: Some(err),
+                    metadata: None,
+                });
+                continue;
+            }
+        };
+        if options.follow_symlinks && file_type.is_symlink() {
+            if let Ok(metadata) = fs::metadata(&path) {
+                file_type = metadata.file_type();
+            }
+        }
+        let entry_type = EntryType::from(file_type);
+        let (metadata, error) = if read_metadata && entry_type == EntryType::File {
+            match child.metadata() {
+                Ok(metadata) => (Some(metadata), None),
+                Err(err) => (None, Some(err)),
+            }
+        } else {
+            (None, None)
+        };
+        let is_dir = entry_type == EntryType::Dir;
+        let index = entries.len();
+        entries.push(WalkEntry {
+            path: path.clone(),
+            depth,
+            entry_type,
+            error,
+            metadata,
+        });
+
+        if !is_dir || depth >= options.max_depth {
+            continue;
+        }
+
+        let id = if options.follow_symlinks {
+            match fs::metadata(&path) {
+                // A followed symlink can point back to an ancestor, don't loop forever
+                Ok(metadata) if ancestors.contains(&file_id(&metadata)) => continue,
+                Ok(metadata) => Some(file_id(&metadata)),
+                Err(err) => {
+                    entries[index].error = Some(err);
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+        let grandchildren = match read_children(&path, options) {
+            Ok(grandchildren) => grandchildren,
+            Err(err) => {
+                entries[index].error = Some(err);
+                continue;
+            }
+        };
+
+        ancestors.extend(id);
+        walk_dir(
+            &path,
+            grandchildren,
+            depth + 1,
+            options,
+            read_metadata,
+            ancestors,
+            entries,
+        );
+        if id.is_some() {
+            ancestors.pop();
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! This crate provides common functionality for Roc to handle Unix signals.
+//!
+//! The signal handler only writes the signal number to a pipe, which is one of the few
+//! things that are safe to do inside a handler. [poll] and [wait] read them back.
+
+use std::io;
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+use std::sync::Mutex;
+
+/// The signals a Roc program can listen for and send.
+/// Roc type: [Hangup, Interrupt, Terminate, User1]
+/// Alphabetically: Hangup=0, Interrupt=1, Terminate=2, User1=3
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Signal {
+    Hangup = 0,
+    Interrupt = 1,
+    Terminate = 2,
+    User1 = 3,
+}
+
+impl Signal {
+    pub fn from_discriminant(discriminant: u8) -> Option<Self> {
+        match discriminant {
+            0 => Some(Signal::Hangup),
+            1 => Some(Signal::Interrupt),
+            2 => Some(Signal::Terminate),
+            3 => Some(Signal::User1),
+            _ => None,
+        }
+    }
+
+    fn number(self) -> libc::c_int {
+        match self {
+            Signal::Hangup => libc::SIGHUP,
+            Signal::Interrupt => libc::SIGINT,
+            Signal::Terminate => libc::SIGTERM,
+            Signal::User1 => libc::SIGUSR1,
+        }
+    }
+
+    fn from_number(number: libc::c_int) -> Option<Self> {
+        match number {
+            libc::SIGHUP => Some(Signal::Hangup),
+            libc::SIGINT => Some(Signal::Interrupt),
+            libc::SIGTERM => Some(Signal::Terminate),
+            libc::SIGUSR1 => Some(Signal::User1),
+            _ => None,
+        }
+    }
+}
+
+/// Write end of the pipe, read by the signal handler.
+static WRITE_FD: AtomicI32 = AtomicI32::new(-1);
+
+/// Read end of the pipe, created by the first call to [listen] with any signals.
+static READ_FD: Mutex<Option<i32>> = Mutex::new(None);
+
+/// Whether a handler was installed for any signal, so [wait] has something to wait for.
+static LISTENING: AtomicBool = AtomicBool::new(false);
+
+#[cfg(target_os = "macos")]
+fn errno_location() -> *mut libc::c_int {
+    unsafe { libc::__error() }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn errno_location() -> *mut libc::c_int {
+    unsafe { libc::__errno_location() }
+}
+
+extern "C" fn on_signal(number: libc::c_int) {
+    // The handler can run between a failing call and the code that reads its errno
+    let saved_errno = unsafe { *errno_location() };
+    let fd = WRITE_FD.load(Ordering::Relaxed);
+    if fd >= 0 {
+        // If the pipe is full the signal is dropped, the program has plenty left to handle
+        let byte = number as u8;
+        unsafe { libc::write(fd, &byte as *const u8 as *const libc::c_void, 1) };
+    }
+    unsafe { *errno_location() = saved_errno };
+}
+
+fn read_fd() -> Option<i32> {
+    *READ_FD
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Create the pipe, both ends non-blocking so the handler never blocks on a full pipe.
+fn create_pipe() -> io::Result<i32> {
+    let mut guard = READ_FD
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(fd) = *guard {
+        return Ok(fd);
+    }
+
+    let mut fds = [0; 2];
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    for fd in fds {
+        unsafe {
+            libc::fcntl(fd, libc::F_SETFL, libc::O_NONBLOCK);
+            libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+        }
+    }
+
+    WRITE_FD.store(fds[1], Ordering::Relaxed);
+    *guard = Some(fds[0]);
+    Ok(fds[0])
+}
+
+/// Start catching the given signals instead of letting them end the program.
+///
+/// Caught signals queue up until they are read with [poll] or [wait].
+///
+/// Interrupted system calls are not restarted, so a blocking call fails with
+/// [io::ErrorKind::Interrupted] when a caught signal arrives and the program can react to it.
+pub fn listen(signals: &[Signal]) -> io::Result<()> {
+    if signals.is_empty() {
+        return Ok(());
+    }
+    create_pipe()?;
+
+    for signal in signals {
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = on_signal as extern "C" fn(libc::c_int) as libc::sighandler_t;
+            action.sa_flags = 0;
+            libc::sigemptyset(&mut action.sa_mask);
+            if libc::sigaction(signal.number(), &action, std::ptr::null_mut()) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        LISTENING.store(true, Ordering::Relaxed);
+    }
+
+    Ok(())
+}
+
+/// Read one signal from the pipe, if there is one.
+fn read_signal(fd: i32) -> io::Result<Option<Signal>> {
+    loop {
+        let mut byte = 0u8;
+        let read = unsafe { libc::read(fd, &mut byte as *mut u8 as *mut libc::c_void, 1) };
+        if read == 1 {
+            // Only signals we installed the handler for end up in the pipe
+            match Signal::from_number(byte as libc::c_int) {
+                Some(signal) => return Ok(Some(signal)),
+                None => continue,
+            }
+        }
+
+        let err = io::Error::last_os_error();
+        match err.kind() {
+            io::ErrorKind::WouldBlock => return Ok(None),
+            io::ErrorKind::Interrupted => continue,
+            _ => return Err(err),
+        }
+    }
+}
+
+/// Return the signals that arrived since the last call, oldest first, without waiting.
+pub fn poll() -> io::Result<Vec<Signal>> {
+    let Some(fd) = read_fd() else {
+        return Ok(Vec::new());
+    };
+
+    let mut signals = Vec::new();
+    while let Some(signal) = read_signal(fd)? {
+        signals.push(signal);
+    }
+    Ok(signals)
+}
+
+/// Wait until a signal arrives and return it. Signals that arrived earlier are returned first.
+pub fn wait() -> io::Result<Signal> {
+    let fd = read_fd().filter(|_| LISTENING.load(Ordering::Relaxed));
+    let Some(fd) = fd else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "not listening for any signals, so waiting would never end",
+        ));
+    };
+
+    let mut pollfd = libc::pollfd {
+        fd,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    loop {
+        if let Some(signal) = read_signal(fd)? {
+            return Ok(signal);
+        }
+        // The signal we are waiting for interrupts poll itself, so EINTR just means try again
+        if unsafe { libc::poll(&mut pollfd, 1, -1) } == -1 {
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+    }
+}
+
+/// Send a signal to the process with the given pid.
+pub fn send(pid: u32, signal: Signal) -> io::Result<()> {
+    // kill treats 0 and negative pids as process groups, which is not what a pid means
+    let pid = match libc::pid_t::try_from(pid) {
+        Ok(pid) if pid > 0 => pid,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{pid} is not a valid process id"),
+            ))
+        }
+    };
+
+    if unsafe { libc::kill(pid, signal.number()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
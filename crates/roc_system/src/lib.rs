@@ -0,0 +1,521 @@
+//! This crate provides common functionality for Roc to query the operating system and hardware
+//! it is running on.
+
+use roc_std_new::{roc_refcounted_noop_impl, RocList, RocOps, RocRefcounted, RocStr};
+use std::ffi::{CStr, CString, OsStr};
+use std::mem::ManuallyDrop;
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+
+/// Payload of a tag union where only `OTHER(Str)` has a payload.
+#[repr(C)]
+pub union OtherTagPayload {
+    other: ManuallyDrop<RocStr>,
+    none: (),
+}
+
+/// A tag union like `[AARCH64, ARM, OTHER(Str), X64, X86]`, where every tag except
+/// `OTHER` is empty. Tags are numbered alphabetically, so `OTHER` differs per union.
+#[repr(C)]
+pub struct OtherTag {
+    payload: OtherTagPayload, // offset 0 (24 bytes)
+    discriminant: u8,         // offset 24 (1 byte + padding = 32 total)
+}
+
+impl OtherTag {
+    fn tag(discriminant: u8) -> Self {
+        Self {
+            payload: OtherTagPayload { none: () },
+            discriminant,
+        }
+    }
+
+    fn other(discriminant: u8, name: &str, roc_ops: &RocOps) -> Self {
+        Self {
+            payload: OtherTagPayload {
+                other: ManuallyDrop::new(RocStr::from_str(name, roc_ops)),
+            },
+            discriminant,
+        }
+    }
+}
+
+/// Returned by `Env.platform!`.
+/// Roc type: { arch : [AARCH64, ARM, OTHER(Str), X64, X86], os : [LINUX, MACOS, OTHER(Str), WINDOWS] }
+/// Memory layout: both are 32 bytes, so alphabetically: arch, os
+#[repr(C)]
+pub struct RocPlatform {
+    pub arch: OtherTag, // offset 0 (32 bytes), OTHER=2
+    pub os: OtherTag,   // offset 32 (32 bytes), OTHER=2
+}
+
+impl RocRefcounted for RocPlatform {
+    fn inc(&mut self) {
+        for tag in [&mut self.arch, &mut self.os] {
+            if tag.discriminant == 2 {
+                unsafe { (*tag.payload.other).inc() }
+            }
+        }
+    }
+    fn dec(&mut self) {
+        for tag in [&mut self.arch, &mut self.os] {
+            if tag.discriminant == 2 {
+                unsafe { (*tag.payload.other).dec() }
+            }
+        }
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// The architecture and operating system the host was compiled for.
+pub fn platform(roc_ops: &RocOps) -> RocPlatform {
+    let arch = match std::env::consts::ARCH {
+        "aarch64" => OtherTag::tag(0),
+        "arm" => OtherTag::tag(1),
+        "x86_64" => OtherTag::tag(3),
+        "x86" => OtherTag::tag(4),
+        other => OtherTag::other(2, other, roc_ops),
+    };
+    let os = match std::env::consts::OS {
+        "linux" => OtherTag::tag(0),
+        "macos" => OtherTag::tag(1),
+        "windows" => OtherTag::tag(3),
+        other => OtherTag::other(2, other, roc_ops),
+    };
+    RocPlatform { arch, os }
+}
+
+/// Returned by `Env.system_info!`.
+/// Roc type: { cpu_count : U64, hostname : Str, kernel_release : Str, os_version : Str, total_memory_bytes : U64 }
+/// Memory layout: all fields are 8-byte aligned, so alphabetically
+#[repr(C)]
+pub struct RocSystemInfo {
+    pub cpu_count: u64,          // offset 0 (8 bytes)
+    pub hostname: RocStr,        // offset 8 (24 bytes)
+    pub kernel_release: RocStr,  // offset 32 (24 bytes)
+    pub os_version: RocStr,      // offset 56 (24 bytes)
+    pub total_memory_bytes: u64, // offset 80 (8 bytes)
+}
+
+impl RocRefcounted for RocSystemInfo {
+    fn inc(&mut self) {
+        self.hostname.inc();
+        self.kernel_release.inc();
+        self.os_version.inc();
+    }
+    fn dec(&mut self) {
+        self.hostname.dec();
+        self.kernel_release.dec();
+        self.os_version.dec();
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// Details about the machine and OS. Anything that can't be determined is `0` or empty.
+pub fn system_info(roc_ops: &RocOps) -> RocSystemInfo {
+    let (hostname, kernel_release) = uname().unwrap_or_default();
+    RocSystemInfo {
+        cpu_count: cpu_count(),
+        hostname: RocStr::from_str(&hostname, roc_ops),
+        kernel_release: RocStr::from_str(&kernel_release, roc_ops),
+        os_version: RocStr::from_str(&os_version().unwrap_or_default(), roc_ops),
+        total_memory_bytes: total_memory_bytes(),
+    }
+}
+
+/// The number of CPUs this process can use, which respects CPU affinity and cgroup limits.
+pub fn cpu_count() -> u64 {
+    std::thread::available_parallelism().map_or(1, |n| n.get() as u64)
+}
+
+/// Physical memory in bytes.
+pub fn total_memory_bytes() -> u64 {
+    let (pages, page_size) = unsafe {
+        (
+            libc::sysconf(libc::_SC_PHYS_PAGES),
+            libc::sysconf(libc::_SC_PAGESIZE),
+        )
+    };
+    if pages < 0 || page_size < 0 {
+        return 0;
+    }
+    (pages as u64).saturating_mul(page_size as u64)
+}
+
+/// The hostname and kernel release, like `uname -n` and `uname -r`.
+pub fn uname() -> Option<(String, String)> {
+    let mut name: libc::utsname = unsafe { std::mem::zeroed() };
+    if unsafe { libc::uname(&mut name) } != 0 {
+        return None;
+    }
+    let field = |chars: &[libc::c_char]| {
+        unsafe { CStr::from_ptr(chars.as_ptr()) }
+            .to_string_lossy()
+            .into_owned()
+    };
+    Some((field(&name.nodename), field(&name.release)))
+}
+
+/// The version of the OS distribution, like `22.04` for Ubuntu 22.04, from os-release.
+#[cfg(not(target_os = "macos"))]
+pub fn os_version() -> Option<String> {
+    let contents = std::fs::read_to_string("/etc/os-release")
+        .or_else(|_| std::fs::read_to_string("/usr/lib/os-release"))
+        .ok()?;
+    contents.lines().find_map(|line| {
+        let value = line.strip_prefix("VERSION_ID=")?;
+        Some(value.trim_matches(|c| c == '"' || c == '\'').to_string())
+    })
+}
+
+/// The version of macOS, like `14.5`.
+#[cfg(target_os = "macos")]
+pub fn os_version() -> Option<String> {
+    let mut buffer = [0u8; 64];
+    let mut len = buffer.len();
+    let result = unsafe {
+        libc::sysctlbyname(
+            c"kern.osproductversion".as_ptr(),
+            buffer.as_mut_ptr() as *mut libc::c_void,
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+    if result != 0 {
+        return None;
+    }
+    let version = CStr::from_bytes_until_nul(&buffer[..len]).ok()?;
+    Some(version.to_string_lossy().into_owned())
+}
+
+/// A locale like `en-US`, split into its language and region.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// The full BCP 47 tag, like `en-US` or `zh-Hans-CN`.
+    pub tag: String,
+    /// The lowercase language code, like `en`.
+    pub language: String,
+    /// The uppercase region code, like `US`, or empty if the tag has none.
+    pub region: String,
+}
+
+impl Locale {
+    /// Parse a BCP 47 tag like `en-US`, or a POSIX locale like `en_US.UTF-8@euro`.
+    pub fn parse(name: &str) -> Option<Self> {
+        // Drop the POSIX encoding and modifier, then use BCP 47 separators
+        let name = name.split(['.', '@']).next().unwrap_or_default();
+        let subtags: Vec<&str> = name.split(['-', '_']).collect();
+        let language = subtags[0];
+        // Also rejects `C` and `POSIX`, which are not languages
+        if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic())
+        {
+            return None;
+        }
+        let language = language.to_ascii_lowercase();
+
+        // The region follows the language and optional 4 letter script, like `zh-Hans-CN`
+        let region = subtags[1..]
+            .iter()
+            .take(2)
+            .find(|subtag| {
+                (subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+                    || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+            })
+            .map(|region| region.to_ascii_uppercase())
+            .unwrap_or_default();
+
+        let tag = std::iter::once(language.clone())
+            .chain(subtags[1..].iter().map(|subtag| {
+                if subtag.eq_ignore_ascii_case(&region) {
+                    region.clone()
+                } else {
+                    subtag.to_string()
+                }
+            }))
+            .collect::<Vec<_>>()
+            .join("-");
+
+        Some(Self {
+            tag,
+            language,
+            region,
+        })
+    }
+}
+
+/// The user's preferred locales, most preferred first.
+///
+/// The first of `LC_ALL`, `LC_MESSAGES` and `LANG` that is set overrides the system
+/// preference on every platform, like it does for C programs. `C` and `POSIX` are ignored,
+/// as they mean no locale was picked.
+pub fn locales() -> Vec<Locale> {
+    let from_env = ["LC_ALL", "LC_MESSAGES", "LANG"].iter().find_map(|name| {
+        let value = std::env::var(name).ok()?;
+        match value.as_str() {
+            "" | "C" | "POSIX" => None,
+            _ => Some(value),
+        }
+    });
+
+    let mut locales: Vec<Locale> = Vec::new();
+    for name in from_env.into_iter().chain(sys_locale::get_locales()) {
+        if let Some(locale) = Locale::parse(&name) {
+            if !locales.contains(&locale) {
+                locales.push(locale);
+            }
+        }
+    }
+    locales
+}
+
+/// Element of the list returned by `Env.locales!`.
+/// Roc type: { language : Str, region : Str, tag : Str }
+/// Memory layout: all fields are Str, so alphabetically: language, region, tag
+#[repr(C)]
+pub struct RocLocale {
+    pub language: RocStr, // offset 0 (24 bytes)
+    pub region: RocStr,   // offset 24 (24 bytes)
+    pub tag: RocStr,      // offset 48 (24 bytes)
+}
+
+impl RocRefcounted for RocLocale {
+    fn inc(&mut self) {
+        self.language.inc();
+        self.region.inc();
+        self.tag.inc();
+    }
+    fn dec(&mut self) {
+        self.language.dec();
+        self.region.dec();
+        self.tag.dec();
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+pub fn locales_to_roc(locales: &[Locale], roc_ops: &RocOps) -> RocList<RocLocale> {
+    let mut list = RocList::with_capacity(locales.len(), roc_ops);
+    for locale in locales {
+        list.push(
+            RocLocale {
+                language: RocStr::from_str(&locale.language, roc_ops),
+                region: RocStr::from_str(&locale.region, roc_ops),
+                tag: RocStr::from_str(&locale.tag, roc_ops),
+            },
+            roc_ops,
+        );
+    }
+    list
+}
+
+/// An entry of the user database, from `/etc/passwd` or a directory service.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct User {
+    pub name: String,
+    pub uid: u32,
+    pub gid: u32,
+    pub home_dir: PathBuf,
+    pub shell: String,
+}
+
+/// Returned by `Env.current_user!` and `Env.user_by_name!`.
+/// Roc type: { gid : U32, home_dir : Path, name : Str, shell : Str, uid : U32 }
+/// Memory layout: Path and Str (8-byte aligned) > U32 (4-byte aligned), so: home_dir, name, shell, gid, uid
+#[repr(C)]
+pub struct RocUser {
+    pub home_dir: RocList<u8>, // offset 0 (24 bytes)
+    pub name: RocStr,          // offset 24 (24 bytes)
+    pub shell: RocStr,         // offset 48 (24 bytes)
+    pub gid: u32,              // offset 72 (4 bytes)
+    pub uid: u32,              // offset 76 (4 bytes)
+}
+
+impl RocRefcounted for RocUser {
+    fn inc(&mut self) {
+        self.home_dir.inc();
+        self.name.inc();
+        self.shell.inc();
+    }
+    fn dec(&mut self) {
+        self.home_dir.dec();
+        self.name.dec();
+        self.shell.dec();
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// Copy a path into a new RocList<u8>, the layout of a Roc `Path`.
+pub fn path_to_roc(path: &OsStr, roc_ops: &RocOps) -> RocList<u8> {
+    let bytes = path.as_bytes();
+    let mut list = RocList::with_capacity(bytes.len(), roc_ops);
+    for &byte in bytes {
+        list.push(byte, roc_ops);
+    }
+    list
+}
+
+impl User {
+    pub fn to_roc(&self, roc_ops: &RocOps) -> RocUser {
+        RocUser {
+            home_dir: path_to_roc(self.home_dir.as_os_str(), roc_ops),
+            name: RocStr::from_str(&self.name, roc_ops),
+            shell: RocStr::from_str(&self.shell, roc_ops),
+            gid: self.gid,
+            uid: self.uid,
+        }
+    }
+}
+
+pub fn uid() -> u32 {
+    unsafe { libc::getuid() }
+}
+
+pub fn gid() -> u32 {
+    unsafe { libc::getgid() }
+}
+
+/// Look up the user the process runs as.
+pub fn current_user() -> Option<User> {
+    // SAFETY: getpwuid_r only writes into the passwd struct and the buffer
+    lookup_user(|passwd, buffer, result| unsafe {
+        libc::getpwuid_r(
+            libc::getuid(),
+            passwd,
+            buffer.as_mut_ptr(),
+            buffer.len(),
+            result,
+        )
+    })
+}
+
+/// Look up a user by login name.
+pub fn user_by_name(name: &str) -> Option<User> {
+    let name = CString::new(name).ok()?;
+    // SAFETY: getpwnam_r only writes into the passwd struct and the buffer
+    lookup_user(|passwd, buffer, result| unsafe {
+        libc::getpwnam_r(
+            name.as_ptr(),
+            passwd,
+            buffer.as_mut_ptr(),
+            buffer.len(),
+            result,
+        )
+    })
+}
+
+/// Call getpwuid_r or getpwnam_r, growing the buffer for the strings until they fit.
+fn lookup_user(
+    getpw: impl Fn(&mut libc::passwd, &mut [libc::c_char], &mut *mut libc::passwd) -> libc::c_int,
+) -> Option<User> {
+    let mut buffer: Vec<libc::c_char> = vec![0; 1024];
+    loop {
+        let mut passwd: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result = std::ptr::null_mut();
+        match getpw(&mut passwd, &mut buffer, &mut result) {
+            0 if result.is_null() => return None,
+            0 => {
+                let bytes = |ptr: *const libc::c_char| {
+                    if ptr.is_null() {
+                        &[][..]
+                    } else {
+                        unsafe { CStr::from_ptr(ptr) }.to_bytes()
+                    }
+                };
+                let field = |ptr| String::from_utf8_lossy(bytes(ptr)).into_owned();
+                return Some(User {
+                    name: field(passwd.pw_name),
+                    uid: passwd.pw_uid,
+                    gid: passwd.pw_gid,
+                    home_dir: PathBuf::from(OsStr::from_bytes(bytes(passwd.pw_dir))),
+                    shell: field(passwd.pw_shell),
+                });
+            }
+            libc::ERANGE if buffer.len() < 1024 * 1024 => buffer.resize(buffer.len() * 2, 0),
+            _ => return None,
+        }
+    }
+}
+
+/// The home directory from `$HOME`, or from the user database if that is not set.
+pub fn home_dir() -> Option<PathBuf> {
+    match std::env::var_os("HOME") {
+        Some(home) if !home.is_empty() => Some(PathBuf::from(home)),
+        _ => current_user()
+            .map(|user| user.home_dir)
+            .filter(|home| !home.as_os_str().is_empty()),
+    }
+}
+
+/// The XDG base directories, see <https://specifications.freedesktop.org/basedir-spec/latest/>.
+#[derive(Clone, Copy, Debug)]
+pub enum XdgDir {
+    Cache,
+    Config,
+    Data,
+    Runtime,
+}
+
+/// The base directory from its `XDG_*` variable, or the default below the home directory.
+///
+/// Relative paths in the variables are ignored, as the spec requires. The runtime
+/// directory has no default.
+pub fn xdg_dir(dir: XdgDir) -> Option<PathBuf> {
+    let (var, default) = match dir {
+        XdgDir::Cache => ("XDG_CACHE_HOME", Some(".cache")),
+        XdgDir::Config => ("XDG_CONFIG_HOME", Some(".config")),
+        XdgDir::Data => ("XDG_DATA_HOME", Some(".local/share")),
+        XdgDir::Runtime => ("XDG_RUNTIME_DIR", None),
+    };
+    match std::env::var_os(var) {
+        Some(path) if path.as_bytes().starts_with(b"/") => Some(PathBuf::from(path)),
+        _ => {
+            let default = default?;
+            Some(home_dir()?.join(default))
+        }
+    }
+}
+
+/// Returned by `Process.resource_usage!`.
+/// Roc type: { max_rss_bytes : U64, system_cpu_nanos : U64, user_cpu_nanos : U64 }
+/// Memory layout: all fields are U64, so alphabetically
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(C)]
+pub struct ResourceUsage {
+    pub max_rss_bytes: u64,    // offset 0 (8 bytes)
+    pub system_cpu_nanos: u64, // offset 8 (8 bytes)
+    pub user_cpu_nanos: u64,   // offset 16 (8 bytes)
+}
+
+roc_refcounted_noop_impl!(ResourceUsage);
+
+/// CPU time and peak memory use of this process so far, from getrusage.
+pub fn resource_usage() -> std::io::Result<ResourceUsage> {
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let nanos =
+        |time: libc::timeval| time.tv_sec as u64 * 1_000_000_000 + time.tv_usec as u64 * 1_000;
+
+    // Linux reports the peak resident set size in kilobytes, macOS in bytes
+    let max_rss = usage.ru_maxrss as u64;
+    let max_rss_bytes = if cfg!(target_os = "macos") {
+        max_rss
+    } else {
+        max_rss * 1024
+    };
+
+    Ok(ResourceUsage {
+        max_rss_bytes,
+        system_cpu_nanos: nanos(usage.ru_stime),
+        user_cpu_nanos: nanos(usage.ru_utime),
+    })
+}
@@ -0,0 +1,637 @@
+//! This crate provides common functionality for Roc to watch files and directories for changes.
+//!
+//! Changes are read from inotify on Linux. Other platforms report `Unsupported`.
+
+use roc_dir::os_str_to_roc_path;
+use roc_std_new::{RocList, RocOps, RocRefcounted};
+use std::collections::HashSet;
+use std::io;
+use std::mem::ManuallyDrop;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What happened to a path.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    Created,
+    Deleted,
+    Modified,
+    /// The kernel dropped events because too many happened at once, `path` is the watched
+    /// path. Anything below it may have changed without an event, so it should be rescanned.
+    Overflow,
+    /// Moved or renamed, `from` is the old path.
+    Renamed {
+        from: PathBuf,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Event {
+    pub kind: EventKind,
+    pub path: PathBuf,
+}
+
+/// Payload of `[Created, Deleted, Modified, Overflow, Renamed(Path)]`, only Renamed has one.
+/// Path is the record { bytes : List(U8) }, which has the same layout as a List(U8).
+#[repr(C)]
+pub union RocEventKindPayload {
+    renamed: ManuallyDrop<RocList<u8>>,
+    none: (),
+}
+
+/// Roc type: [Created, Deleted, Modified, Overflow, Renamed(Path)]
+/// Alphabetically: Created=0, Deleted=1, Modified=2, Overflow=3, Renamed=4
+#[repr(C)]
+pub struct RocEventKind {
+    payload: RocEventKindPayload, // offset 0 (24 bytes)
+    discriminant: u8,             // offset 24 (1 byte + padding = 32 total)
+}
+
+impl RocEventKind {
+    const RENAMED: u8 = 4;
+
+    fn new(kind: &EventKind, roc_ops: &RocOps) -> Self {
+        let (payload, discriminant) = match kind {
+            EventKind::Created => (RocEventKindPayload { none: () }, 0),
+            EventKind::Deleted => (RocEventKindPayload { none: () }, 1),
+            EventKind::Modified => (RocEventKindPayload { none: () }, 2),
+            EventKind::Overflow => (RocEventKindPayload { none: () }, 3),
+            EventKind::Renamed { from } => {
+                let from = os_str_to_roc_path(from.as_os_str(), roc_ops);
+                (
+                    RocEventKindPayload {
+                        renamed: ManuallyDrop::new(from),
+                    },
+                    Self::RENAMED,
+                )
+            }
+        };
+        Self {
+            payload,
+            discriminant,
+        }
+    }
+}
+
+impl RocRefcounted for RocEventKind {
+    fn inc(&mut self) {
+        if self.discriminant == Self::RENAMED {
+            unsafe { (*self.payload.renamed).inc() }
+        }
+    }
+    fn dec(&mut self) {
+        if self.discriminant == Self::RENAMED {
+            unsafe { (*self.payload.renamed).dec() }
+        }
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// Event returned by `Watch.poll!` and `Watch.wait!`.
+/// Roc type: { kind : [Created, Deleted, Modified, Overflow, Renamed(Path)], path : Path }
+/// Memory layout: both are 8-byte aligned, so alphabetically: kind, path
+#[repr(C)]
+pub struct RocEvent {
+    pub kind: RocEventKind, // offset 0 (32 bytes)
+    pub path: RocList<u8>,  // offset 32 (24 bytes)
+}
+
+impl RocRefcounted for RocEvent {
+    fn inc(&mut self) {
+        self.kind.inc();
+        self.path.inc();
+    }
+    fn dec(&mut self) {
+        self.kind.dec();
+        self.path.dec();
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// Convert events to a Roc list. Paths keep their bytes, even if they are not valid UTF-8.
+pub fn events_to_roc(events: &[Event], roc_ops: &RocOps) -> RocList<RocEvent> {
+    let mut list = RocList::with_capacity(events.len(), roc_ops);
+    for event in events {
+        list.push(
+            RocEvent {
+                kind: RocEventKind::new(&event.kind, roc_ops),
+                path: os_str_to_roc_path(event.path.as_os_str(), roc_ops),
+            },
+            roc_ops,
+        );
+    }
+    list
+}
+
+/// Options for `Watch.start!`: { recursive : Bool }
+#[repr(C)]
+pub struct WatchOptions {
+    pub recursive: bool,
+}
+
+/// Merge the bursts of events a single save tends to produce: a path that was
+/// created or modified earlier in the batch is not reported again until it is deleted.
+/// This also drops the duplicate Created events a new directory can cause, see `add_new_dir`.
+fn coalesce(events: Vec<Event>) -> Vec<Event> {
+    let mut changed = HashSet::new();
+    events
+        .into_iter()
+        .filter(|event| match event.kind {
+            EventKind::Created | EventKind::Modified => changed.insert(event.path.clone()),
+            EventKind::Deleted | EventKind::Overflow | EventKind::Renamed { .. } => {
+                changed.remove(&event.path);
+                true
+            }
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+mod inotify {
+    use super::{coalesce, Event, EventKind};
+    use std::collections::HashMap;
+    use std::ffi::{CString, OsStr};
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex, OnceLock};
+    use std::time::{Duration, Instant};
+
+    /// IN_CLOSE_WRITE, IN_ATTRIB and IN_MOVE_SELF are needed to notice an editor replacing
+    /// a watched file through a rename, which never modifies the original file.
+    const MASK: u32 = libc::IN_ATTRIB
+        | libc::IN_CLOSE_WRITE
+        | libc::IN_CREATE
+        | libc::IN_DELETE
+        | libc::IN_DELETE_SELF
+        | libc::IN_MODIFY
+        | libc::IN_MOVE_SELF
+        | libc::IN_MOVED_FROM
+        | libc::IN_MOVED_TO;
+
+    /// How many debounce windows [wait] keeps collecting for at most, so a file that is
+    /// written continuously can't keep it from returning.
+    const MAX_DEBOUNCE_WINDOWS: u32 = 10;
+
+    /// Size of `struct inotify_event` without the trailing name.
+    const EVENT_HEADER_SIZE: usize = std::mem::size_of::<libc::inotify_event>();
+
+    /// An inotify instance and the directory each of its watch descriptors belongs to.
+    struct Watcher {
+        fd: i32,
+        root: PathBuf,
+        root_is_dir: bool,
+        recursive: bool,
+        watches: HashMap<i32, PathBuf>,
+    }
+
+    impl Drop for Watcher {
+        fn drop(&mut self) {
+            unsafe { libc::close(self.fd) };
+        }
+    }
+
+    /// A raw inotify event, before it is attached to a path.
+    struct RawEvent {
+        wd: i32,
+        mask: u32,
+        cookie: u32,
+        name: Vec<u8>,
+    }
+
+    /// Watchers by id, the id is the handle Roc holds on to. Ids are never reused, so a
+    /// stopped watcher can't end up pointing at a newer one.
+    fn watchers() -> &'static Mutex<HashMap<u64, Arc<Mutex<Watcher>>>> {
+        static WATCHERS: OnceLock<Mutex<HashMap<u64, Arc<Mutex<Watcher>>>>> = OnceLock::new();
+        WATCHERS.get_or_init(|| Mutex::new(HashMap::new()))
+    }
+
+    static NEXT_WATCHER_ID: AtomicU64 = AtomicU64::new(1);
+
+    /// Run `f` on a watcher. The table is only locked to look the watcher up, so a
+    /// blocking [wait] doesn't hold up other watchers.
+    fn with_watcher<T>(id: u64, f: impl FnOnce(&mut Watcher) -> io::Result<T>) -> io::Result<T> {
+        let watcher = watchers()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&id)
+            .cloned();
+        match watcher {
+            Some(watcher) => {
+                let mut watcher = watcher
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                f(&mut watcher)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "this watcher was already stopped",
+            )),
+        }
+    }
+
+    pub fn start(path: &Path, recursive: bool) -> io::Result<u64> {
+        let is_dir = std::fs::metadata(path)?.is_dir();
+
+        let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC | libc::IN_NONBLOCK) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Dropping the watcher closes the descriptor if adding the watches fails
+        let mut watcher = Watcher {
+            fd,
+            root: path.to_path_buf(),
+            root_is_dir: is_dir,
+            recursive: recursive && is_dir,
+            watches: HashMap::new(),
+        };
+        watcher.add_tree(path)?;
+
+        let id = NEXT_WATCHER_ID.fetch_add(1, Ordering::Relaxed);
+        watchers()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(id, Arc::new(Mutex::new(watcher)));
+        Ok(id)
+    }
+
+    pub fn poll(id: u64) -> io::Result<Vec<Event>> {
+        with_watcher(id, |watcher| {
+            let raw = watcher.read_available()?;
+            Ok(watcher.process(raw))
+        })
+    }
+
+    pub fn wait(id: u64, debounce: Duration) -> io::Result<Vec<Event>> {
+        with_watcher(id, |watcher| loop {
+            wait_readable(watcher.fd, None)?;
+            let mut raw = watcher.read_available()?;
+            // Keep collecting until nothing happened for a whole debounce window,
+            // or until the time for all debounce windows together is up
+            let deadline =
+                Instant::now().checked_add(debounce.saturating_mul(MAX_DEBOUNCE_WINDOWS));
+            loop {
+                let window = match deadline {
+                    Some(deadline) => {
+                        debounce.min(deadline.saturating_duration_since(Instant::now()))
+                    }
+                    None => debounce,
+                };
+                if window.is_zero() {
+                    break;
+                }
+                match wait_readable(watcher.fd, Some(window)) {
+                    Ok(true) => raw.extend(watcher.read_available()?),
+                    Ok(false) => break,
+                    // The events are already read, so return them instead of the interruption
+                    Err(err) if err.kind() == io::ErrorKind::Interrupted => break,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            // Only bookkeeping events (like a removed watch) arrived, keep waiting
+            let events = watcher.process(raw);
+            if !events.is_empty() {
+                return Ok(events);
+            }
+        })
+    }
+
+    /// The descriptor is closed when the watcher is dropped.
+    pub fn stop(id: u64) {
+        watchers()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&id);
+    }
+
+    /// Block until the inotify descriptor has events, or the timeout passes.
+    ///
+    /// Fails with [io::ErrorKind::Interrupted] when a caught signal arrives, so a program
+    /// listening for Ctrl-C does not keep waiting for changes.
+    fn wait_readable(fd: i32, timeout: Option<Duration>) -> io::Result<bool> {
+        let timeout_ms = match timeout {
+            Some(timeout) => i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX),
+            None => -1,
+        };
+        let mut pollfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        match unsafe { libc::poll(&mut pollfd, 1, timeout_ms) } {
+            -1 => Err(io::Error::last_os_error()),
+            ready => Ok(ready > 0),
+        }
+    }
+
+    impl Watcher {
+        fn add_watch(&mut self, path: &Path) -> io::Result<()> {
+            let c_path = CString::new(path.as_os_str().as_bytes())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            let wd = unsafe { libc::inotify_add_watch(self.fd, c_path.as_ptr(), MASK) };
+            if wd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            self.watches.insert(wd, path.to_path_buf());
+            Ok(())
+        }
+
+        /// Watch `path`, and every directory below it when watching recursively.
+        fn add_tree(&mut self, path: &Path) -> io::Result<()> {
+            self.add_watch(path)?;
+            if self.recursive {
+                for entry in roc_dir::walk(path, &walk_options())? {
+                    if entry.entry_type == roc_dir::EntryType::Dir {
+                        self.add_watch(&entry.path)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        /// Watch a directory that just appeared. Anything created inside it before the
+        /// watch was in place would be missed, so report its contents as created.
+        fn add_new_dir(&mut self, dir: &Path, events: &mut Vec<Event>) {
+            // The directory may already be gone again, that shows up as a Deleted event
+            if self.add_watch(dir).is_err() {
+                return;
+            }
+            let Ok(entries) = roc_dir::walk(dir, &walk_options()) else {
+                return;
+            };
+            for entry in entries {
+                if entry.entry_type == roc_dir::EntryType::Dir {
+                    let _ = self.add_watch(&entry.path);
+                }
+                events.push(Event {
+                    kind: EventKind::Created,
+                    path: entry.path,
+                });
+            }
+        }
+
+        /// Update the paths of watched directories after `from` was renamed to `to`.
+        fn rename_watches(&mut self, from: &Path, to: &Path) {
+            for path in self.watches.values_mut() {
+                if let Ok(rest) = path.strip_prefix(from) {
+                    *path = if rest.as_os_str().is_empty() {
+                        to.to_path_buf()
+                    } else {
+                        to.join(rest)
+                    };
+                }
+            }
+        }
+
+        /// Handle the watched path itself being deleted or moved away through the watch `wd`.
+        ///
+        /// Editors often save a file by writing a new one and renaming it over the old one,
+        /// so if a watched file is replaced like that, the new file is watched instead and the
+        /// change is reported as Modified.
+        fn root_removed(&mut self, wd: i32, events: &mut Vec<Event>) {
+            // After a move the watch still follows the old file, wherever it went
+            self.watches.remove(&wd);
+            unsafe { libc::inotify_rm_watch(self.fd, wd) };
+
+            let root = self.root.clone();
+            let replaced = !self.root_is_dir
+                && std::fs::metadata(&root).is_ok_and(|metadata| !metadata.is_dir())
+                && self.add_watch(&root).is_ok();
+            events.push(Event {
+                kind: if replaced {
+                    EventKind::Modified
+                } else {
+                    EventKind::Deleted
+                },
+                path: root,
+            });
+        }
+
+        /// Stop watching `dir` and everything below it, after it was moved out of the tree.
+        fn remove_watches(&mut self, dir: &Path) {
+            let fd = self.fd;
+            self.watches.retain(|&wd, path| {
+                let keep = !path.starts_with(dir);
+                if !keep {
+                    unsafe { libc::inotify_rm_watch(fd, wd) };
+                }
+                keep
+            });
+        }
+
+        /// Read all events that are available without blocking.
+        fn read_available(&self) -> io::Result<Vec<RawEvent>> {
+            // Big enough for many events, and aligned for inotify_event
+            let mut buffer = vec![0u64; 8192];
+            let mut events = Vec::new();
+            loop {
+                let len = unsafe {
+                    libc::read(
+                        self.fd,
+                        buffer.as_mut_ptr() as *mut libc::c_void,
+                        buffer.len() * std::mem::size_of::<u64>(),
+                    )
+                };
+                if len < 0 {
+                    let err = io::Error::last_os_error();
+                    match err.kind() {
+                        io::ErrorKind::WouldBlock => return Ok(events),
+                        io::ErrorKind::Interrupted => continue,
+                        _ => return Err(err),
+                    }
+                }
+
+                let bytes = unsafe {
+                    std::slice::from_raw_parts(buffer.as_ptr() as *const u8, len as usize)
+                };
+                let mut offset = 0;
+                while offset + EVENT_HEADER_SIZE <= bytes.len() {
+                    let header = unsafe {
+                        std::ptr::read_unaligned(
+                            bytes[offset..].as_ptr() as *const libc::inotify_event
+                        )
+                    };
+                    let name_start = offset + EVENT_HEADER_SIZE;
+                    let name_end = name_start + header.len as usize;
+                    // The name is padded with NUL bytes
+                    let name = bytes[name_start..name_end]
+                        .split(|&b| b == 0)
+                        .next()
+                        .unwrap_or_default()
+                        .to_vec();
+                    events.push(RawEvent {
+                        wd: header.wd,
+                        mask: header.mask,
+                        cookie: header.cookie,
+                        name,
+                    });
+                    offset = name_end;
+                }
+            }
+        }
+
+        /// Turn raw events into events with full paths, pairing up the two halves of a rename.
+        fn process(&mut self, raw: Vec<RawEvent>) -> Vec<Event> {
+            let mut events = Vec::new();
+            let mut moved_from: Vec<(u32, PathBuf)> = Vec::new();
+
+            for event in raw {
+                if event.mask & libc::IN_Q_OVERFLOW != 0 {
+                    events.push(Event {
+                        kind: EventKind::Overflow,
+                        path: self.root.clone(),
+                    });
+                    continue;
+                }
+                if event.mask & libc::IN_IGNORED != 0 {
+                    self.watches.remove(&event.wd);
+                    continue;
+                }
+                let Some(dir) = self.watches.get(&event.wd) else {
+                    continue;
+                };
+                let path = if event.name.is_empty() {
+                    dir.clone()
+                } else {
+                    dir.join(OsStr::from_bytes(&event.name))
+                };
+                let is_dir = event.mask & libc::IN_ISDIR != 0;
+
+                if event.mask & libc::IN_CREATE != 0 {
+                    events.push(Event {
+                        kind: EventKind::Created,
+                        path: path.clone(),
+                    });
+                    if is_dir && self.recursive {
+                        self.add_new_dir(&path, &mut events);
+                    }
+                } else if event.mask & (libc::IN_MODIFY | libc::IN_CLOSE_WRITE | libc::IN_ATTRIB)
+                    != 0
+                {
+                    events.push(Event {
+                        kind: EventKind::Modified,
+                        path,
+                    });
+                } else if event.mask & libc::IN_DELETE != 0 {
+                    events.push(Event {
+                        kind: EventKind::Deleted,
+                        path,
+                    });
+                } else if event.mask & libc::IN_MOVED_FROM != 0 {
+                    moved_from.push((event.cookie, path));
+                } else if event.mask & libc::IN_MOVED_TO != 0 {
+                    match moved_from
+                        .iter()
+                        .position(|(cookie, _)| *cookie == event.cookie)
+                    {
+                        Some(index) => {
+                            let (_, from) = moved_from.remove(index);
+                            if is_dir {
+                                self.rename_watches(&from, &path);
+                            }
+                            events.push(Event {
+                                kind: EventKind::Renamed { from },
+                                path,
+                            });
+                        }
+                        // Moved in from outside the watched tree
+                        None => {
+                            events.push(Event {
+                                kind: EventKind::Created,
+                                path: path.clone(),
+                            });
+                            if is_dir && self.recursive {
+                                self.add_new_dir(&path, &mut events);
+                            }
+                        }
+                    }
+                } else if event.mask & (libc::IN_DELETE_SELF | libc::IN_MOVE_SELF) != 0
+                    && path == self.root
+                {
+                    // Deleting or moving anything else is already reported by its parent directory
+                    self.root_removed(event.wd, &mut events);
+                }
+            }
+
+            // Moved out of the watched tree
+            for (_, from) in moved_from {
+                self.remove_watches(&from);
+                events.push(Event {
+                    kind: EventKind::Deleted,
+                    path: from,
+                });
+            }
+
+            coalesce(events)
+        }
+    }
+
+    fn walk_options() -> roc_dir::WalkOptions {
+        roc_dir::WalkOptions {
+            max_depth: u64::MAX,
+            follow_symlinks: false,
+            skip_hidden: false,
+            sort: false,
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod inotify {
+    use super::Event;
+    use std::io;
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            "watching for changes is only supported on Linux",
+        )
+    }
+
+    pub fn start(_path: &Path, _recursive: bool) -> io::Result<u64> {
+        Err(unsupported())
+    }
+
+    pub fn poll(_id: u64) -> io::Result<Vec<Event>> {
+        Err(unsupported())
+    }
+
+    pub fn wait(_id: u64, _debounce: Duration) -> io::Result<Vec<Event>> {
+        Err(unsupported())
+    }
+
+    pub fn stop(_id: u64) {}
+}
+
+/// Start watching a file or directory, returning the handle for [poll], [wait] and [stop].
+///
+/// With `recursive`, directories below `path` are watched too, including ones created later.
+pub fn start(path: &std::path::Path, recursive: bool) -> io::Result<u64> {
+    inotify::start(path, recursive)
+}
+
+/// Return the events that happened since the last call, without waiting.
+pub fn poll(handle: u64) -> io::Result<Vec<Event>> {
+    inotify::poll(handle)
+}
+
+/// Wait for events, then keep collecting them until nothing happens for `debounce`.
+///
+/// Collecting stops after ten debounce windows even if events keep arriving.
+pub fn wait(handle: u64, debounce: Duration) -> io::Result<Vec<Event>> {
+    inotify::wait(handle, debounce)
+}
+
+/// Stop watching. Stopping a watcher twice does nothing.
+pub fn stop(handle: u64) {
+    inotify::stop(handle)
+}
@@ -0,0 +1,109 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.Dir
+import pf.File
+import pf.Env
+import pf.Path
+
+# Demo of Dir.walk!, Dir.walk_respecting_ignores!, Dir.entries!, Dir.glob!, Dir.disk_usage! and Dir.copy_all!
+
+main! = |_args| {
+    demo = Path.from_str("walk-demo")
+    copy = Path.from_str("walk-copy")
+
+    Dir.create_all!(Path.from_str("walk-demo/src/utils"))?
+    File.write_utf8!(Path.from_str("walk-demo/README.md"), "# Demo")?
+    File.write_utf8!(Path.from_str("walk-demo/src/main.roc"), "")?
+    File.write_utf8!(Path.from_str("walk-demo/src/utils/str.roc"), "")?
+    File.write_utf8!(Path.from_str("walk-demo/.hidden"), "")?
+    File.write_utf8!(Path.from_str("walk-demo/.gitignore"), "*.md\n")?
+
+    # Everything except hidden entries, sorted by name
+    entries = Dir.walk!(demo, { ..Dir.walk_defaults, skip_hidden: Bool.True })?
+
+    List.for_each!(entries, |entry| {
+        type =
+            match entry.type {
+                Dir => "dir"
+                File => "file"
+                Symlink => "symlink"
+                Other => "other"
+                Unreadable(_) => "unreadable"
+            }
+        Stdout.line!("${entry.path.display()} (${type}, depth ${entry.depth.to_str()})")
+    })
+
+    # Only the immediate children, hidden ones included
+    children = Dir.walk!(demo, { ..Dir.walk_defaults, max_depth: 1 })?
+    Stdout.line!("Immediate children: ${List.len(children).to_str()}")
+
+    # Leave out what .gitignore excludes, like ripgrep does
+    not_ignored = Dir.walk_respecting_ignores!(demo, { ..Dir.walk_defaults, skip_hidden: Bool.True })?
+    Stdout.line!("Not ignored: ${Str.join_with(not_ignored.map(|entry| entry.name.display()), ", ")}")
+
+    # Immediate children with their type, in no particular order
+    dirs =
+        Dir.entries!(demo)?
+            .keep_if(
+                |entry|
+                    match entry.type {
+                        Dir => Bool.True
+                        _ => Bool.False
+                    },
+            )
+            .map(|entry| entry.name.display())
+    Stdout.line!("Directories: ${Str.join_with(dirs, ", ")}")
+
+    # Pattern matching over the filesystem
+    roc_files = Dir.glob!("walk-demo/**/*.{roc,md}")?
+    Stdout.line!("Glob: ${Str.join_with(roc_files.map(Path.display), ", ")}")
+
+    # Total size of the files, hidden ones included
+    usage = Dir.disk_usage!(demo)?
+    Stdout.line!("Disk usage: ${usage.bytes.to_str()} bytes in ${usage.files.to_str()} files")
+
+    # Copy the whole tree, then again, skipping files that did not change
+    first_copy = Dir.copy_all!(demo, copy, { skip_unchanged: Bool.False })?
+    second_copy = Dir.copy_all!(demo, copy, { skip_unchanged: Bool.True })?
+    Stdout.line!("First copy: ${first_copy.copied.to_str()} copied, second copy: ${second_copy.skipped.to_str()} skipped")
+
+    Dir.delete_all!(copy)?
+    Dir.delete_all!(demo)?
+
+    # A directory that can't be listed is reported in its entry, and the walk goes on
+    partly_locked = Path.from_str("walk-locked")
+    locked = partly_locked.join(Path.from_str("locked"))
+    Dir.create_all!(locked)?
+    File.write_utf8!(partly_locked.join(Path.from_str("open.txt")), "")?
+    File.set_permissions!(locked, 0o000)?
+
+    walked = Dir.walk!(partly_locked, Dir.walk_defaults)?
+    reported =
+        List.any(
+            walked,
+            |entry|
+                match entry.type {
+                    Unreadable(_) => Bool.True
+                    _ => Bool.False
+                },
+        )
+    # Root can list any directory
+    Stdout.line!("Unreadable directory reported: ${Str.inspect(reported or Env.uid!({}) == 0)}")
+    Stdout.line!("Walked entries: ${List.len(walked).to_str()}")
+
+    # Its size is unknown, so disk_usage! fails instead of giving a total that is too small
+    usage_failed =
+        match Dir.disk_usage!(partly_locked) {
+            Ok(_) => Bool.False
+            Err(_) => Bool.True
+        }
+    Stdout.line!("disk_usage! fails on an unreadable directory: ${Str.inspect(usage_failed or Env.uid!({}) == 0)}")
+
+    File.set_permissions!(locked, 0o755)?
+    Dir.delete_all!(partly_locked)?
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
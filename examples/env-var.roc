@@ -2,18 +2,85 @@ app [main!] { pf: platform "../platform/main.roc" }
 
 import pf.Stdout
 import pf.Env
+import pf.Path
 
-# How to read environment variables with Env.var!
+# How to read and change environment variables, and find out about the user and system, with the Env module
 
-main! : List(Str) => Try({}, [Exit(I32)])
 main! = |_args| {
-    editor = Env.var!("EDITOR")
-
-    if Str.is_empty(editor) {
-        Stdout.line!("EDITOR is not set")
-    } else {
-        Stdout.line!("Your favorite editor is ${editor}!")
+    match Env.var!("EDITOR") {
+        Ok(editor) => Stdout.line!("Your favorite editor is ${editor}!")
+        Err(VarNotFound) => Stdout.line!("EDITOR is not set")
+        Err(NotUnicode(_)) => Stdout.line!("EDITOR is not valid Unicode")
     }
 
+    # Parse a comma-separated list
+    letters = Env.decode!("LETTERS", Env.list(Env.str))?
+    Stdout.line!("Your favorite letters are: ${Str.join_with(letters, " ")}")
+
+    # Fall back to a default when the variable is not set
+    shell = Env.var_or!("ROC_EXAMPLE_SHELL", "sh")
+    Stdout.line!("Using shell ${shell}")
+
+    # Changes are seen by this program and by commands it runs afterwards
+    Env.set_var!("GREETING", "hello")?
+    Stdout.line!("GREETING: ${Str.inspect(Env.var!("GREETING"))}")
+    Stdout.line!("GREETING as a number: ${Str.inspect(Env.decode!("GREETING", Env.u64))}")
+    Env.remove_var!("GREETING")?
+    Stdout.line!("GREETING after removing: ${Str.inspect(Env.var!("GREETING"))}")
+
+    # The smallest I64 is fine, one more digit is out of range instead of overflowing
+    Env.set_var!("OFFSET", "-9223372036854775808")?
+    Stdout.line!("OFFSET: ${Str.inspect(Env.decode!("OFFSET", Env.i64))}")
+    Env.set_var!("OFFSET", "-92233720368547758080")?
+    Stdout.line!("OFFSET with another digit: ${Str.inspect(Env.decode!("OFFSET", Env.i64))}")
+
+    Env.set_var!("TIMEOUT", "30s")?
+    timeout = Env.decode!("TIMEOUT", Env.duration_ms)?
+    Stdout.line!("Timeout: ${timeout.to_str()}ms")
+
+    # What the program is running on
+    supported_os =
+        match Env.platform!({}).os {
+            LINUX => Bool.True
+            MACOS => Bool.True
+            _ => Bool.False
+        }
+    Stdout.line!("Running on Linux or macOS: ${Str.inspect(supported_os)}")
+    info = Env.system_info!({})
+    Stdout.line!("Has CPUs and memory: ${Str.inspect(info.cpu_count > 0 and info.total_memory_bytes > 0)}")
+
+    # Where to store per-user files
+    show_dir = |dir|
+        match dir {
+            Ok(path) => path.display()
+            Err(NotAvailable) => "not available"
+        }
+    Stdout.line!("Home dir: ${show_dir(Env.home_dir!({}))}")
+    Stdout.line!("Config dir: ${show_dir(Env.config_dir!({}))}")
+    Stdout.line!("Config file: ${Env.config_dir!({})?.join(Path.from_str("my-cli/config.toml")).display()}")
+    Stdout.line!("Cache dir: ${show_dir(Env.cache_dir!({}))}")
+    Stdout.line!("Runtime dir: ${show_dir(Env.runtime_dir!({}))}")
+    found_by_name =
+        match Env.current_user!({}) {
+            Ok(user) =>
+                match Env.user_by_name!(user.name) {
+                    Ok(same_user) => same_user.uid == Env.uid!({})
+                    Err(UserNotFound) => Bool.False
+                }
+            # Containers often run as a uid with no entry in the user database
+            Err(UserNotFound) => Bool.True
+        }
+    Stdout.line!("Found the current user by name: ${Str.inspect(found_by_name)}")
+
+    # All variables at once, as a list of pairs or as a dictionary
+    Env.set_var!("ROC_EXAMPLE_COLOR", "green")?
+    listed = List.contains(Env.vars!({}), ("ROC_EXAMPLE_COLOR", "green"))
+    Stdout.line!("vars! has ROC_EXAMPLE_COLOR: ${Str.inspect(listed)}")
+    Stdout.line!("dict! has ROC_EXAMPLE_COLOR: ${Str.inspect(Dict.contains(Env.dict!({}), "ROC_EXAMPLE_COLOR"))}")
+
+    # Relative paths start from the working directory, which the program can change
+    Env.set_cwd!(Path.from_str("/"))?
+    Stdout.line!("Working directory: ${Env.cwd!({}).display()}")
+
     Ok({})
 }
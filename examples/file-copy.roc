@@ -0,0 +1,44 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Path
+
+# Demo of File.copy!, File.copy_with! and File.move!
+
+main! = |_args| {
+    original = Path.from_str("copy-original.txt")
+    plain = Path.from_str("copy-plain.txt")
+    preserved = Path.from_str("copy-preserved.txt")
+    moved_to = Path.from_str("copy-moved.txt")
+
+    File.write_utf8!(original, "Hello, copy!")?
+
+    # Copy contents and permissions
+    File.copy!(original, plain)?
+
+    # Also keep the timestamps (and owner, if permitted)
+    File.copy_with!(original, preserved, { preserve_metadata: Bool.True })?
+
+    # Rename, or copy and delete when moving across filesystems
+    File.move!(plain, moved_to)?
+
+    moved = File.read_utf8!(moved_to)?
+    Stdout.line!("Moved file contains: ${moved}")
+
+    # Checking a path that does not exist fails with NotFound
+    plain_exists =
+        match Path.is_file!(plain) {
+            Ok(is_file) => is_file
+            Err(_) => Bool.False
+        }
+    Stdout.line!("Original of moved file still exists: ${Str.inspect(plain_exists)}")
+
+    File.delete!(original)?
+    File.delete!(preserved)?
+    File.delete!(moved_to)?
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
@@ -0,0 +1,54 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Path
+
+# Demo of File.lock_exclusive!, File.try_lock!, File.try_lock_shared!, File.unlock! and File.with_lock!
+
+main! = |_args| {
+    path = Path.from_str("lock-demo.lock")
+
+    # Locking a file this program already holds an exclusive lock on fails right away
+    lock = File.lock_exclusive!(path)?
+    Stdout.line!("Exclusive lock, then try_lock!: ${attempt!(File.try_lock!(path))}")
+    Stdout.line!("Exclusive lock, then try_lock_shared!: ${attempt!(File.try_lock_shared!(path))}")
+    File.unlock!(lock)
+
+    # Shared locks can be taken any number of times
+    shared = File.try_lock_shared!(path)?
+    Stdout.line!("Shared lock, then try_lock_shared!: ${attempt!(File.try_lock_shared!(path))}")
+
+    # The exclusive lock was already released, so this must leave the shared lock alone
+    File.unlock!(lock)
+    Stdout.line!("Shared lock after unlocking twice, then try_lock!: ${attempt!(File.try_lock!(path))}")
+    File.unlock!(shared)
+
+    Stdout.line!("Released lock, then try_lock!: ${attempt!(File.try_lock!(path))}")
+
+    # Without the check, the inner with_lock! would wait for the outer one forever
+    nested_failed =
+        match File.with_lock!(path, |{}| File.with_lock!(path, |{}| {})) {
+            Ok(Ok({})) => Bool.False
+            Ok(Err(_)) => Bool.True
+            Err(_) => Bool.True
+        }
+    Stdout.line!("Nested with_lock! fails: ${Str.inspect(nested_failed)}")
+
+    File.delete!(path)?
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
+
+# What happened when trying to take the lock, releasing it if it was taken
+attempt! = |result|
+    match result {
+        Ok(taken) => {
+            File.unlock!(taken)
+            "taken"
+        }
+        Err(WouldBlock) => "another program holds a lock"
+        Err(FileErr(_)) => "failed"
+    }
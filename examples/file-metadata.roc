@@ -0,0 +1,29 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Env
+import pf.Path
+
+# Demo of File.set_permissions!, File.chown! and File.set_times!
+# The expect script checks the resulting permissions and times of metadata-demo.txt
+
+main! = |_args| {
+    path = Path.from_str("metadata-demo.txt")
+    File.write_utf8!(path, "metadata")?
+
+    # Setting the times only needs write permission, not read permission
+    File.set_permissions!(path, 0o200)?
+    # 2001-09-09T01:46:40Z in nanoseconds since the Unix epoch
+    File.set_times!(path, 1_000_000_000_000_000_000, 1_000_000_000_000_000_000)?
+    Stdout.line!("Set the times of a write-only file")
+
+    # Giving a file to our own user and group needs no privileges
+    File.chown!(path, Env.uid!({}), Env.gid!({}))?
+    Stdout.line!("Changed the owner to ourselves")
+
+    File.set_permissions!(path, 0o640)?
+    Stdout.line!("Made the file readable again")
+
+    Ok({})
+}
@@ -0,0 +1,50 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Path
+
+# Demo of File.map!, File.read_mapping! and File.unmap!
+
+main! = |_args| {
+    path = Path.from_str("mmap-demo.txt")
+    File.write_utf8!(path, "Hello from a mapped file!")?
+
+    mapping = File.map!(path)?
+    Stdout.line!("Mapped bytes: ${File.mapping_len(mapping).to_str()}")
+
+    first = File.read_mapping!(mapping, 0, 5)?
+    Stdout.line!("First chunk: ${Str.from_utf8(first)?}")
+
+    # Reads are clamped to the end of the file
+    last = File.read_mapping!(mapping, 20, 100)?
+    Stdout.line!("Last chunk: ${Str.from_utf8(last)?}")
+
+    past_end = File.read_mapping!(mapping, 100, 10)?
+    Stdout.line!("Bytes past the end: ${List.len(past_end).to_str()}")
+
+    # A file that shrinks while mapped gives fewer bytes instead of crashing
+    File.write_utf8!(path, "Hello")?
+    shrunk = File.read_mapping!(mapping, 0, 100)?
+    Stdout.line!("After truncating: ${Str.from_utf8(shrunk)?}")
+
+    File.unmap!(mapping)
+    # Releasing twice does nothing
+    File.unmap!(mapping)
+
+    released =
+        match File.read_mapping!(mapping, 0, 5) {
+            Ok(_) => "still readable"
+            Err(FileErr(_)) => "fails"
+        }
+    Stdout.line!("Reading a released mapping: ${released}")
+
+    size = File.with_mapping!(path, |mapped| File.mapping_len(mapped))?
+    Stdout.line!("Size through with_mapping!: ${size.to_str()}")
+
+    File.delete!(path)?
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
@@ -0,0 +1,57 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Dir
+import pf.Path
+import pf.Process
+
+# Demo of File.move! across filesystems, where it has to copy and delete
+
+main! = |_args| {
+    # /dev/shm is an in-memory filesystem on Linux, elsewhere the move is a plain rename
+    shm = Path.from_str("/dev/shm")
+    on_shm =
+        match Path.is_dir!(shm) {
+            Ok(is_dir) => is_dir
+            Err(_) => Bool.False
+        }
+    base = if on_shm { shm } else { Path.from_str(".") }
+
+    dir = base.join(Path.from_str("file-move-${Process.pid!({}).to_str()}"))
+    Dir.create!(dir)?
+
+    source = Path.from_str("move-source.txt")
+    dest = dir.join(Path.from_str("moved.txt"))
+    File.write_utf8!(source, "Moved across filesystems")?
+    File.write_utf8!(dest, "Old contents")?
+
+    File.move!(source, dest)?
+    Stdout.line!("Destination contains: ${File.read_utf8!(dest)?}")
+
+    source_exists =
+        match Path.is_file!(source) {
+            Ok(is_file) => is_file
+            Err(_) => Bool.False
+        }
+    Stdout.line!("Source still exists: ${Str.inspect(source_exists)}")
+
+    # Copying a directory onto a file fails, which must leave the existing file alone
+    source_dir = Path.from_str("move-source-dir")
+    Dir.create!(source_dir)?
+    failed =
+        match File.move!(source_dir, dest) {
+            Ok({}) => Bool.False
+            Err(_) => Bool.True
+        }
+    Stdout.line!("Moving a directory onto a file failed: ${Str.inspect(failed)}")
+    Stdout.line!("Destination still contains: ${File.read_utf8!(dest)?}")
+    Stdout.line!("Files next to the destination: ${List.len(Dir.list!(dir)?).to_str()}")
+
+    Dir.delete_empty!(source_dir)?
+    Dir.delete_all!(dir)?
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
@@ -0,0 +1,47 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Dir
+import pf.Path
+
+# Demo of Path.symlink!, Path.read_link!, Path.hard_link! and Path.canonicalize!
+
+main! = |_args| {
+    dir = Path.from_str("links-demo")
+    Dir.create_all!(dir)?
+    target = dir.join(Path.from_str("target.txt"))
+    File.write_utf8!(target, "linked")?
+
+    # A relative target is resolved from the directory the link is in
+    link = dir.join(Path.from_str("link.txt"))
+    Path.symlink!(Path.from_str("target.txt"), link)?
+    Stdout.line!("Is a symlink: ${Str.inspect(Path.is_sym_link!(link)?)}")
+    Stdout.line!("Link points to: ${Path.read_link!(link)?.display()}")
+    Stdout.line!("Read through link: ${File.read_utf8!(link)?}")
+
+    # Link targets that are not valid UTF-8 come back unchanged, the target does not need to exist
+    odd_target = Path.from_bytes(List.append(Str.to_utf8("old"), 0xFF))
+    odd_link = dir.join(Path.from_str("odd-link"))
+    Path.symlink!(odd_target, odd_link)?
+    odd_read = Path.read_link!(odd_link)?
+    Stdout.line!("Odd target unchanged: ${Str.inspect(odd_read.to_bytes() == odd_target.to_bytes())}")
+
+    # Both names refer to the same contents
+    hard = dir.join(Path.from_str("hard.txt"))
+    Path.hard_link!(target, hard)?
+    File.write_utf8!(hard, "changed through the hard link")?
+    Stdout.line!("Target contains: ${File.read_utf8!(target)?}")
+
+    # Resolving the symlink gives the absolute path of its target
+    canonical_link = Path.canonicalize!(link)?
+    canonical_target = Path.canonicalize!(target)?
+    Stdout.line!("Canonical path is absolute: ${Str.inspect(canonical_link.is_absolute())}")
+    Stdout.line!("Link resolves to target: ${Str.inspect(canonical_link.to_bytes() == canonical_target.to_bytes())}")
+
+    Dir.delete_all!(dir)?
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
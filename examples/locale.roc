@@ -1,16 +1,21 @@
 app [main!] { pf: platform "../platform/main.roc" }
 
 import pf.Stdout
-import pf.Locale
+import pf.Env
 
 # Getting the preferred locale and all available locales
 
 main! = |_args| {
-    locale_str = Locale.get!()
-    Stdout.line!("The most preferred locale for this system or application: ${locale_str}")
+    match Env.locale!({}) {
+        Ok(locale) => {
+            Stdout.line!("The most preferred locale for this system or application: ${locale.tag}")
+            Stdout.line!("Language: ${locale.language}, region: ${locale.region}")
+        }
+        Err(NotAvailable) => Stdout.line!("No locale is configured")
+    }
 
-    all_locales = Locale.all!()
-    Stdout.line!("All available locales: ${Inspect.to_str(all_locales)}")
+    all_locales = Env.locales!({}).map(|locale| locale.tag)
+    Stdout.line!("All available locales for this system or application: ${Str.inspect(all_locales)}")
 
     Ok({})
-}
\ No newline at end of file
+}
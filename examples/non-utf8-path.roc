@@ -0,0 +1,57 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Dir
+import pf.Path
+import pf.Cmd
+
+# Demo of a file name that is not valid UTF-8, going through File, Dir, Path and Cmd unchanged
+
+main! = |_args| {
+    dir = Path.from_str("non-utf8-demo")
+    Dir.create_all!(dir)?
+
+    # "café" with the é encoded in Latin-1, which is not valid UTF-8
+    name = List.concat(Str.to_utf8("caf"), [0xE9])
+    path = dir.join(Path.from_bytes(name))
+
+    valid =
+        match path.to_str() {
+            Ok(_) => "yes"
+            Err(BadUtf8) => "no"
+        }
+    Stdout.line!("Valid UTF-8: ${valid}")
+
+    File.write_utf8!(path, "Bonjour!")?
+    Stdout.line!("Is a file: ${Str.inspect(Path.is_file!(path)?)}")
+
+    # Listing the directory gives back the exact bytes
+    listed = Dir.list!(dir)?
+    Stdout.line!("Listed path unchanged: ${Str.inspect(listed.map(Path.to_bytes) == [path.to_bytes()])}")
+
+    names = Dir.entries!(dir)?.map(|entry| entry.name.to_bytes())
+    Stdout.line!("Entry name unchanged: ${Str.inspect(names == [name])}")
+
+    walked = Dir.walk!(dir, Dir.walk_defaults)?.map(|entry| entry.path.to_bytes())
+    Stdout.line!("Walked path unchanged: ${Str.inspect(walked == [path.to_bytes()])}")
+
+    # So a listed path can be used to open the file again
+    read_back =
+        match listed {
+            [listed_path] => File.read_utf8!(listed_path)?
+            _ => ""
+        }
+    Stdout.line!("Read back: ${read_back}")
+
+    # A command gets the path as an argument byte for byte, so it can open the file too
+    cat_output = Cmd.new("cat").arg_path(path).exec_output!()?
+    Stdout.line!("Read by cat: ${cat_output.stdout_utf8}")
+
+    File.delete!(path)?
+    Dir.delete_empty!(dir)?
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
@@ -7,7 +7,7 @@ import pf.Path
 
 main! : List(Str) => Try({}, [Exit(I32)])
 main! = |_args| {
-    path = "path.roc"
+    path = Path.from_str("path.roc")
 
     a = Path.is_file!(path)
     b = Path.is_dir!(path)
@@ -19,5 +19,39 @@ main! = |_args| {
         \\is_sym_link: ${Str.inspect(c)}
     )
 
+    # Space on the filesystem the example runs on
+    stats = Path.filesystem_stats!(Path.from_str("."))?
+    fits = stats.available_bytes <= stats.free_bytes and stats.free_bytes <= stats.total_bytes
+    Stdout.line!("available <= free <= total: ${Str.inspect(fits)}")
+
+    # Pure path manipulation, no filesystem access
+    source = Path.from_str("src/utils/../lib").join(Path.from_str("main.roc"))
+
+    Stdout.line!(
+        \\join: ${source.display()}
+        \\normalize: ${source.normalize().display()}
+        \\parent: ${or_none(source.parent())}
+        \\file_name: ${or_none(source.file_name())}
+        \\stem: ${or_none(source.stem())}
+        \\extension: ${or_none(source.extension())}
+        \\with_extension: ${source.with_extension("md").display()}
+        \\relative_to: ${or_none(Path.relative_to(Path.from_str("docs/index.md"), Path.from_str("src/utils")))}
+        \\matches_glob: ${Str.inspect(source.matches_glob("src/**/[a-m]*.{roc,md}"))}
+    )
+
+    # Wildcards match whole characters, and many stars don't make matching slow
+    many_a = Path.from_str("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa")
+    Stdout.line!(
+        \\matches_glob with ?: ${Str.inspect(Path.from_str("menu/café.txt").matches_glob("*/caf?.txt"))}
+        \\matches_glob with a class: ${Str.inspect(Path.from_str("café").matches_glob("caf[à-ï]"))}
+        \\matches_glob with many stars: ${Str.inspect(many_a.matches_glob("*a*a*a*a*a*a*a*a*a*a*b"))}
+    )
+
     Ok({})
 }
+
+or_none = |result|
+    match result {
+        Ok(path) => path.display()
+        Err(_) => "none"
+    }
@@ -0,0 +1,24 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.Process
+
+# Demo of Process.pid!, Process.parent_pid!, Process.resource_usage! and Process.exit!
+
+main! = |_args| {
+    pid = Process.pid!({})
+    parent_pid = Process.parent_pid!({})
+    Stdout.line!("Has a pid: ${Str.inspect(pid > 0)}")
+    Stdout.line!("Parent differs: ${Str.inspect(parent_pid != pid)}")
+
+    usage = Process.resource_usage!({})?
+    Stdout.line!("Used memory: ${Str.inspect(usage.max_rss_bytes > 0)}")
+
+    # Exit right away with status 3, main! never gets to return
+    Stdout.line!("Exiting from inside main!")
+    Process.exit!(3)
+
+    Stdout.line!("Never printed")
+
+    Ok({})
+}
@@ -0,0 +1,50 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdin
+import pf.Stdout
+import pf.Process
+import pf.Signal
+import pf.Sleep
+
+# Demo of Signal.listen!, Signal.send!, Signal.poll! and Signal.wait!
+
+main! = |_args| {
+    # Listening for no signals leaves wait! nothing to wait for, so it fails right away
+    Signal.listen!([])?
+    wait_failed =
+        match Signal.wait!({}) {
+            Ok(_) => Bool.False
+            Err(_) => Bool.True
+        }
+    Stdout.line!("Waiting without signals fails: ${Str.inspect(wait_failed)}")
+
+    Signal.listen!([Interrupt, Hangup, User1])?
+    Stdout.line!("Nothing yet: ${Str.inspect(Signal.poll!({})?)}")
+
+    # Without listen! these would end the program
+    pid = Process.pid!({})
+    Signal.send!(pid, User1)?
+    Signal.send!(pid, Hangup)?
+    Stdout.line!("Received: ${Str.inspect(Signal.poll!({})?)}")
+
+    Signal.send!(pid, Interrupt)?
+    signal = Signal.wait!({})?
+    Stdout.line!("Waited for: ${Str.inspect(signal)}")
+
+    # A caught signal interrupts blocking calls, so Ctrl-C still gets through
+    Stdout.line!("Press Ctrl-C to stop waiting for input")
+    input_interrupted =
+        match Stdin.line!({}) {
+            Ok(_) => Bool.False
+            Err(_) => Bool.True
+        }
+    Stdout.line!("Input interrupted: ${Str.inspect(input_interrupted)}, by ${Str.inspect(Signal.poll!({})?)}")
+
+    Stdout.line!("Press Ctrl-C to stop sleeping")
+    Sleep.millis!(60_000)
+    Stdout.line!("Sleep interrupted by ${Str.inspect(Signal.poll!({})?)}")
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
@@ -3,14 +3,27 @@ app [main!] { pf: platform "../platform/main.roc" }
 import pf.Stdin
 import pf.Stdout
 
-main! : List(Str) => Try({}, [Exit(I32)])
 main! = |_args| {
     Stdout.line!("What's your first name?")
-    first = Stdin.line!({})
+    first = Stdin.line!({})?
 
     Stdout.line!("What's your last name?")
-    last = Stdin.line!({})
+    last = Stdin.line!({})?
 
     Stdout.line!("Hi, ${first} ${last}! \u(1F44B)")
+
+    Stdout.line!("Any hobbies? Enter one per line, Ctrl-D when done.")
+    hobbies = read_lines!([])?
+    Stdout.line!("You have ${List.len(hobbies).to_str()} hobbies: ${Str.join_with(hobbies, ", ")}")
+
     Ok({})
 }
+
+# Keep reading lines until stdin is closed
+read_lines! = |lines| {
+    match Stdin.line!({}) {
+        Ok(line) => read_lines!(List.append(lines, line))
+        Err(EndOfFile) => Ok(lines)
+        Err(StdinErr(err)) => Err(StdinErr(err))
+    }
+}
@@ -0,0 +1,59 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Dir
+import pf.Path
+
+# Demo of File.create_temp!, File.with_temp!, Dir.create_temp! and Dir.with_temp!
+
+main! = |_args| {
+    # A temp file that we clean up ourselves
+    scratch = File.create_temp!("scratch-")?
+    File.write_utf8!(scratch, "draft")?
+    File.delete!(scratch)?
+
+    # A temp file that is deleted when the callback returns
+    (contents, temp_file) = File.with_temp!(
+        "greeting-",
+        |path| {
+            _ = File.write_utf8!(path, "Hello from a temp file!")
+            (File.read_utf8!(path), path)
+        },
+    )?
+    Stdout.line!("Read back: ${Str.inspect(contents)}")
+    Stdout.line!("Temp file still exists: ${Str.inspect(exists!(temp_file))}")
+
+    # A temp directory that is deleted with everything in it
+    temp_dir = Dir.with_temp!(
+        "build-",
+        |dir| {
+            _ = File.write_utf8!(dir.join(Path.from_str("out.txt")), "output")
+            dir
+        },
+    )?
+    Stdout.line!("Temp dir still exists: ${Str.inspect(exists!(temp_dir))}")
+
+    other_dir = Dir.create_temp!("build-")?
+    Stdout.line!("Temp dirs are unique: ${Str.inspect(other_dir.to_bytes() != temp_dir.to_bytes())}")
+    Dir.delete_empty!(other_dir)?
+
+    # A prefix can't point outside the temp directory
+    rejected =
+        match File.create_temp!("../escape-") {
+            Ok(_) => Bool.False
+            Err(_) => Bool.True
+        }
+    Stdout.line!("Prefix with .. rejected: ${Str.inspect(rejected)}")
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
+
+# Any of the Path checks fails with NotFound once the path is gone
+exists! = |path|
+    match Path.is_sym_link!(path) {
+        Ok(_) => Bool.True
+        Err(_) => Bool.False
+    }
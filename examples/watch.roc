@@ -0,0 +1,59 @@
+app [main!] { pf: platform "../platform/main.roc" }
+
+import pf.Stdout
+import pf.File
+import pf.Dir
+import pf.Path
+import pf.Watch
+
+# Demo of Watch.start!, Watch.poll!, Watch.wait! and Watch.stop!
+
+main! = |_args| {
+    demo_dir = Path.from_str("watch-demo")
+    Dir.create_all!(demo_dir)?
+    watcher = Watch.start!(demo_dir, { recursive: Bool.True })?
+
+    # Changes made before polling are queued up
+    notes = Path.from_str("watch-demo/notes.txt")
+    old = Path.from_str("watch-demo/drafts/old.txt")
+    File.write_utf8!(notes, "first draft")?
+    File.write_utf8!(notes, "second draft")?
+    Dir.create!(Path.from_str("watch-demo/drafts"))?
+    File.write_utf8!(old, "old")?
+
+    queued = Watch.poll!(watcher)?
+    List.for_each!(queued, print_event!)
+
+    File.move!(notes, Path.from_str("watch-demo/drafts/notes.txt"))?
+    File.delete!(old)?
+
+    # Waits for the first change, then until nothing changed for 100ms
+    changes = Watch.wait!(watcher, 100)?
+    List.for_each!(changes, print_event!)
+
+    nothing = Watch.poll!(watcher)?
+    Stdout.line!("Changes since last wait: ${Str.inspect(List.len(nothing))}")
+
+    # Paths keep their exact bytes, even when they are not valid UTF-8
+    latin1 = demo_dir.join(Path.from_bytes(List.concat(Str.to_utf8("caf"), [0xE9])))
+    File.write_utf8!(latin1, "")?
+    created = Watch.wait!(watcher, 100)?
+    same_bytes = List.any(created, |event| event.path.to_bytes() == latin1.to_bytes())
+    Stdout.line!("Non-UTF-8 name unchanged: ${Str.inspect(same_bytes)}")
+
+    Watch.stop!(watcher)
+    Dir.delete_all!(demo_dir)?
+
+    Stdout.line!("Success!")
+
+    Ok({})
+}
+
+print_event! = |event|
+    match event.kind {
+        Created => Stdout.line!("created ${event.path.display()}")
+        Deleted => Stdout.line!("deleted ${event.path.display()}")
+        Modified => Stdout.line!("modified ${event.path.display()}")
+        Overflow => Stdout.line!("too many changes below ${event.path.display()}")
+        Renamed(from) => Stdout.line!("renamed ${from.display()} -> ${event.path.display()}")
+    }
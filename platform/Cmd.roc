@@ -1,5 +1,5 @@
 Cmd :: {
-    args : List(Str),
+    args : List(List(U8)),
     clear_envs : Bool,
     envs : List(Str),
     program : Str,
@@ -26,7 +26,7 @@ Cmd :: {
     ## ```
     arg : Cmd, Str -> Cmd
     arg = |cmd, a| {
-        args: List.append(cmd.args, a),
+        args: List.append(cmd.args, Str.to_utf8(a)),
         clear_envs: cmd.clear_envs,
         envs: cmd.envs,
         program: cmd.program,
@@ -39,7 +39,22 @@ Cmd :: {
     ## ```
     args : Cmd, List(Str) -> Cmd
     args = |cmd, new_args| {
-        args: List.concat(cmd.args, new_args),
+        args: List.concat(cmd.args, List.map(new_args, Str.to_utf8)),
+        clear_envs: cmd.clear_envs,
+        envs: cmd.envs,
+        program: cmd.program,
+    }
+
+    ## Add a path as a single argument to the command.
+    ##
+    ## The path is passed unchanged, even if it is not valid UTF-8.
+    ##
+    ## ```roc
+    ## cmd = Cmd.new("wc").arg("-l").arg_path(path)
+    ## ```
+    arg_path : Cmd, Path -> Cmd
+    arg_path = |cmd, path| {
+        args: List.append(cmd.args, path.to_bytes()),
         clear_envs: cmd.clear_envs,
         envs: cmd.envs,
         program: cmd.program,
@@ -23,26 +23,221 @@ Dir := [].{
     ##
     ## If the parent directories do not exist, they will not be created.
     ## Use [Dir.create_all!] to create parent directories as needed.
-    create! : Str => Try({}, [DirErr(IOErr)])
+    create! : Path => Try({}, [DirErr(IOErr)])
 
     ## Creates a new, empty directory at the provided path, including any parent directories.
     ##
     ## If the directory already exists, this will succeed without error.
-    create_all! : Str => Try({}, [DirErr(IOErr)])
+    create_all! : Path => Try({}, [DirErr(IOErr)])
 
     ## Deletes an empty directory.
     ##
     ## Fails if the directory is not empty. Use [Dir.delete_all!] to delete
     ## a directory and all its contents.
-    delete_empty! : Str => Try({}, [DirErr(IOErr)])
+    delete_empty! : Path => Try({}, [DirErr(IOErr)])
 
     ## Deletes a directory and all of its contents recursively.
     ##
     ## Use with caution!
-    delete_all! : Str => Try({}, [DirErr(IOErr)])
+    delete_all! : Path => Try({}, [DirErr(IOErr)])
+
+    ## Copies a directory and everything in it, creating `to` and its parents as needed.
+    ##
+    ## Permissions are preserved and symlinks are copied as symlinks, pointing to the same target.
+    ## Files in `to` that don't exist in `from` are left alone. Special files like sockets are skipped.
+    ##
+    ## With `skip_unchanged`, a file is only copied if it's missing in `to`, has a different size
+    ## or is newer than the copy, which makes repeated copies of a large tree fast:
+    ## ```roc
+    ## Dir.copy_all!(Path.from_str("assets"), Path.from_str("dist/assets"), { skip_unchanged: Bool.True })
+    ## ```
+    ##
+    ## Returns how many files and symlinks were copied and how many were skipped.
+    copy_all! : Path, Path, { skip_unchanged : Bool } => Try({ copied : U64, skipped : U64 }, [DirErr(IOErr)])
+
+    ## Returns the total size in bytes of all files in a directory and its subdirectories,
+    ## and how many files there are. For a file, returns its own size.
+    ##
+    ## Symlinks are not followed, and a file with several hard links is only counted once.
+    ## Sizes are file lengths, like `du --apparent-size`, not the space taken up on disk.
+    ##
+    ## Files deleted while the sizes are being added up are left out. Any other entry that
+    ## can't be read, like a subdirectory without read permission, fails the whole call,
+    ## because the total would be too small without it.
+    ##
+    ## ```roc
+    ## { bytes, files } = Dir.disk_usage!(Path.from_str("target"))?
+    ## ```
+    disk_usage! : Path => Try({ bytes : U64, files : U64 }, [DirErr(IOErr)])
 
     ## Lists the contents of a directory.
     ##
     ## Returns the paths of all files and directories within the specified directory.
-    list! : Str => Try(List(Str), [DirErr(IOErr)])
+    ## Use [Dir.entries!] to also get the type of each entry.
+    list! : Path => Try(List(Path), [DirErr(IOErr)])
+
+    ## An entry of a directory, as returned by [Dir.entries!].
+    ##
+    ## A symlink has type `Symlink` no matter what it points to. An entry whose type could
+    ## not be read, for example because it was deleted while the directory was being listed,
+    ## has type `Unreadable` with the error. If reading the directory itself fails partway
+    ## through, the entries read so far are kept, followed by an `Unreadable` entry with the
+    ## path of the directory.
+    Entry : { name : Path, path : Path, type : [Dir, File, Other, Symlink, Unreadable(IOErr)] }
+
+    ## Lists the contents of a directory, along with the name and type of each entry.
+    ##
+    ## The type comes from the directory listing itself, so there is no need to check each path again.
+    ## An entry that can't be read is kept with an `Unreadable` type instead of failing the whole list.
+    entries! : Path => Try(List(Entry), [DirErr(IOErr)])
+
+    ## Finds all paths matching a glob pattern, sorted by path.
+    ##
+    ## - `*` matches any part of a name, `?` matches a single character.
+    ## - `**` matches any number of directories, e.g. `src/**/*.roc`.
+    ## - `[abc]`, `[a-z]` and `[!0-9]` match one character from (or not from) a set.
+    ## - `{src,tests}/*.roc` matches any of the comma separated alternatives.
+    ##
+    ## Wildcards also match names starting with a `.`. Relative patterns give paths relative
+    ## to the current directory. Matching is done by [Path.matches_glob], which you can use
+    ## to filter paths you already have.
+    glob! : Str => Try(List(Path), [DirErr(IOErr)])
+    glob! = |pattern| {
+        parts = Path.from_str(pattern).components()
+        has_wildcard = |part| List.any(part.to_bytes(), |byte| byte == '*' or byte == '?' or byte == '[' or byte == '{')
+
+        match List.find_first_index(parts, has_wildcard) {
+            # Without wildcards the pattern can only match itself
+            Err(_) => {
+                path = Path.from_components(parts)
+                match Path.is_sym_link!(path) {
+                    Ok(_) => Ok([path])
+                    Err(_) => Ok([])
+                }
+            }
+            Ok(literal_len) => {
+                # Only walk below the leading components without wildcards
+                root = if literal_len == 0 { Path.from_str(".") } else { Path.from_components(List.take_first(parts, literal_len)) }
+                rest = List.drop_first(parts, literal_len)
+                # `**` can match any number of directories, otherwise each component is one level.
+                # Brace alternatives never have more components than the whole pattern.
+                has_double_star = |part| {
+                    scan = List.fold(
+                        part.to_bytes(),
+                        { previous: 0, found: Bool.False },
+                        |state, byte| { previous: byte, found: state.found or (byte == '*' and state.previous == '*') },
+                    )
+                    scan.found
+                }
+                options = {
+                    ..walk_defaults,
+                    max_depth: if List.any(rest, has_double_star) { walk_defaults.max_depth } else { List.len(rest) },
+                }
+                root_is_file =
+                    match Path.is_file!(root) {
+                        Ok(is_file) => is_file
+                        Err(_) => Bool.False
+                    }
+
+                if root_is_file {
+                    Ok([])
+                } else {
+                    match walk!(root, options) {
+                        # Sorted walks list paths in order, parents before their children
+                        Ok(entries) =>
+                            Ok(
+                                entries
+                                    .keep_if(|entry| entry.path.matches_glob(pattern))
+                                    # Walking "." gives "./name", report it as "name"
+                                    .map(|entry| Path.from_components(entry.path.components())),
+                            )
+                        # Nothing can match below a directory that does not exist
+                        Err(DirErr(NotFound)) => Ok([])
+                        Err(err) => Err(err)
+                    }
+                }
+            }
+        }
+    }
+
+    ## Options for [Dir.walk!]:
+    ##
+    ## - **max_depth** - How deep to recurse; `1` only lists the immediate children.
+    ## - **follow_symlinks** - Report the type of a symlink's target and descend into linked directories. Links that point back to an enclosing directory are not followed again.
+    ## - **skip_hidden** - Skip entries whose name starts with a `.`, including everything inside hidden directories.
+    ## - **sort** - Sort the entries of each directory by name.
+    ##
+    ## Start from [Dir.walk_defaults] and override what you need:
+    ## ```roc
+    ## Dir.walk!(Path.from_str("src"), { ..Dir.walk_defaults, skip_hidden: Bool.True })
+    ## ```
+    WalkOptions : { follow_symlinks : Bool, max_depth : U64, skip_hidden : Bool, sort : Bool }
+
+    ## An entry found by [Dir.walk!], like [Dir.Entry] with its depth. Direct children of the starting directory have depth `1`.
+    ##
+    ## The type comes from the directory listing itself, so there is no need to check each path again.
+    ## A directory whose contents could not be listed, or an entry whose type could not be read,
+    ## has type `Unreadable` with the error.
+    WalkEntry : { depth : U64, name : Path, path : Path, type : [Dir, File, Other, Symlink, Unreadable(IOErr)] }
+
+    ## No depth limit, symlinks are not followed, hidden entries are included and entries are sorted by name.
+    walk_defaults : WalkOptions
+    walk_defaults = {
+        follow_symlinks: Bool.False,
+        max_depth: 18_446_744_073_709_551_615,
+        skip_hidden: Bool.False,
+        sort: Bool.True,
+    }
+
+    ## Lists everything inside a directory, recursively.
+    ##
+    ## Each directory is listed before its contents. The starting directory itself is not included.
+    ##
+    ## Only failing to open the starting directory fails the walk. A subdirectory that can't be
+    ## listed, for example because of its permissions, is reported as an `Unreadable` entry
+    ## and the walk goes on with the next entry. Entries that can't be read are reported the
+    ## same way as in [Dir.entries!].
+    walk! : Path, WalkOptions => Try(List(WalkEntry), [DirErr(IOErr)])
+
+    ## Like [Dir.walk!], but skips everything ignored by `.gitignore`, `.ignore` and
+    ## `.git/info/exclude` files, the way `ripgrep` does.
+    ##
+    ## Ignore files apply to the directory they are in and everything below it, including
+    ## ignore files in the parent directories of the starting directory. `.ignore` files
+    ## take precedence over `.gitignore` files, and `!pattern` re-includes a path.
+    ## Unlike `ripgrep`, `.gitignore` files are also respected outside of a git repository.
+    ## Like `git`, your global gitignore file (`core.excludesFile`, or `~/.config/git/ignore`)
+    ## applies as well.
+    ##
+    ## Symlink loops and directories that can't be listed are handled the same way as in [Dir.walk!].
+    ##
+    ## To skip hidden files and directories too (including `.git`), set `skip_hidden`:
+    ## ```roc
+    ## Dir.walk_respecting_ignores!(Path.from_str("."), { ..Dir.walk_defaults, skip_hidden: Bool.True })
+    ## ```
+    walk_respecting_ignores! : Path, WalkOptions => Try(List(WalkEntry), [DirErr(IOErr)])
+
+    ## Creates a new, empty directory with a unique name in the system temp directory
+    ## (`$TMPDIR`, or `/tmp` if that is not set) and returns its path.
+    ##
+    ## The name starts with `prefix`, followed by random characters. The directory is
+    ## only accessible by the current user, and is never one that already existed.
+    ##
+    ## A `prefix` containing `/` or `..` fails with an error, so the directory always ends up
+    ## directly in the temp directory.
+    ##
+    ## The directory is not deleted automatically; use [Dir.with_temp!] for that.
+    create_temp! : Str => Try(Path, [DirErr(IOErr)])
+
+    ## Creates a temporary directory like [Dir.create_temp!], passes its path to the callback,
+    ## and deletes the directory and everything in it when the callback returns, whether it
+    ## succeeded or not.
+    with_temp! : Str, (Path => a) => Try(a, [DirErr(IOErr)])
+    with_temp! = |prefix, callback!| {
+        path = create_temp!(prefix)?
+        result = callback!(path)
+        # The callback may already have removed or moved the directory
+        _ = delete_all!(path)
+        Ok(result)
+    }
 }
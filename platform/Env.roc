@@ -1,17 +1,413 @@
 Env := [].{
+    ## **NotFound** - An entity was not found, often a file.
+    ##
+    ## **PermissionDenied** - The operation lacked the necessary privileges to complete.
+    ##
+    ## **BrokenPipe** - The operation failed because a pipe was closed.
+    ##
+    ## **AlreadyExists** - An entity already exists, often a file.
+    ##
+    ## **Interrupted** - This operation was interrupted. Interrupted operations can typically be retried.
+    ##
+    ## **Unsupported** - This operation is unsupported on this platform. This means that the operation can never succeed.
+    ##
+    ## **OutOfMemory** - An operation could not be completed, because it failed to allocate enough memory.
+    ##
+    ## **Other** - A custom error that does not fall under any other I/O error kind.
+    IOErr := [
+        NotFound,
+        PermissionDenied,
+        BrokenPipe,
+        AlreadyExists,
+        Interrupted,
+        Unsupported,
+        OutOfMemory,
+        Other(Str),
+    ]
+
     ## Reads the given environment variable.
     ##
-    ## If the value is invalid Unicode, the invalid parts will be replaced with the
+    ## Fails with `VarNotFound` if the variable is not set, so an unset variable can be told
+    ## apart from one that is set to an empty string. If the value is not valid Unicode,
+    ## fails with `NotUnicode` holding the raw bytes.
+    ##
+    ## ```roc
+    ## match Env.var!("EDITOR") {
+    ##     Ok(editor) => Stdout.line!("Opening ${editor}")
+    ##     Err(VarNotFound) => Stdout.line!("Set EDITOR to pick an editor")
+    ##     Err(NotUnicode(_)) => Stdout.line!("EDITOR is not valid Unicode")
+    ## }
+    ## ```
+    var! : Str => Try(Str, [NotUnicode(List(U8)), VarNotFound])
+
+    ## Reads the given environment variable like [Env.var!], but returns `default` if it
+    ## is not set or not valid Unicode.
+    ##
+    ## ```roc
+    ## roc_cmd = Env.var_or!("ROC", "roc")
+    ## ```
+    var_or! : Str, Str => Str
+    var_or! = |name, default|
+        match var!(name) {
+            Ok(value) => value
+            Err(_) => default
+        }
+
+    ## Reads an environment variable and parses it with a decoder like [Env.u64], [Env.bool],
+    ## [Env.duration_ms] or [Env.list].
+    ##
+    ## Fails with `EnvVarNotSet` if the variable is not set. If the value can't be parsed, or is
+    ## not valid Unicode, fails with `InvalidEnvVar` holding the name, the value and what the
+    ## decoder expected, so the error can be shown to the user as is:
+    ##
+    ## ```roc
+    ## port = Env.decode!("PORT", Env.u64)?
+    ## timeout = Env.decode!("TIMEOUT", Env.duration_ms)?
+    ## features = Env.decode!("FEATURES", Env.list(Env.str))?
+    ## ```
+    ##
+    ## A decoder is any function that takes the value and returns what it expected on failure,
+    ## like `"a port number"`.
+    decode! : Str, (Str -> Try(a, Str)) => Try(a, [EnvVarNotSet(Str), InvalidEnvVar({ expected : Str, name : Str, value : Str })])
+    decode! = |name, decoder|
+        match var!(name) {
+            Ok(value) =>
+                match decoder(value) {
+                    Ok(decoded) => Ok(decoded)
+                    Err(expected) => Err(InvalidEnvVar({ expected, name, value }))
+                }
+            Err(VarNotFound) => Err(EnvVarNotSet(name))
+            Err(NotUnicode(bytes)) => Err(InvalidEnvVar({ expected: "valid Unicode", name, value: Str.from_utf8_lossy(bytes) }))
+        }
+
+    ## Decodes the value as is, for use with [Env.decode!] and [Env.list].
+    str : Str -> Try(Str, Str)
+    str = |value| Ok(value)
+
+    ## Decodes a non-empty value as a [Path], for use with [Env.decode!] and [Env.list].
+    path : Str -> Try(Path, Str)
+    path = |value| if Str.is_empty(value) { Err("a path") } else { Ok(Path.from_str(value)) }
+
+    # The value of an ASCII digit, shared by [Env.u64] and [Env.i64]
+    digit_value = |byte|
+        match byte {
+            '0' => Ok(0)
+            '1' => Ok(1)
+            '2' => Ok(2)
+            '3' => Ok(3)
+            '4' => Ok(4)
+            '5' => Ok(5)
+            '6' => Ok(6)
+            '7' => Ok(7)
+            '8' => Ok(8)
+            '9' => Ok(9)
+            _ => Err(NotADigit)
+        }
+
+    ## Decodes a non-negative integer like `8080`, for use with [Env.decode!] and [Env.list].
+    u64 : Str -> Try(U64, Str)
+    u64 = |value| {
+        expected = "a non-negative integer"
+        bytes = Str.to_utf8(value)
+        if List.is_empty(bytes) {
+            Err(expected)
+        } else {
+            List.fold(
+                bytes,
+                Ok(0),
+                |acc, byte|
+                    match (acc, digit_value(byte)) {
+                        (Ok(n), Ok(digit)) =>
+                            # Stop before going over 18_446_744_073_709_551_615
+                            if n > 1_844_674_407_370_955_161 or (n == 1_844_674_407_370_955_161 and digit > 5) {
+                                Err("an integer no larger than 18446744073709551615")
+                            } else {
+                                Ok(n * 10 + digit)
+                            }
+                        (Err(e), _) => Err(e)
+                        (_, Err(NotADigit)) => Err(expected)
+                    },
+            )
+        }
+    }
+
+    ## Decodes an integer like `-42`, for use with [Env.decode!] and [Env.list].
+    i64 : Str -> Try(I64, Str)
+    i64 = |value| {
+        expected = "an integer"
+        (negative, digits) =
+            match Str.to_utf8(value) {
+                ['-', .. as rest] => (Bool.True, rest)
+                ['+', .. as rest] => (Bool.False, rest)
+                bytes => (Bool.False, bytes)
+            }
+        if List.is_empty(digits) {
+            Err(expected)
+        } else {
+            List.fold(
+                digits,
+                Ok(0),
+                |acc, byte|
+                    match (acc, digit_value(byte)) {
+                        (Ok(n), Ok(digit)) => {
+                            # Negative numbers are built up below zero, so -9_223_372_036_854_775_808 fits.
+                            # Negating the total instead would overflow once it reached that value.
+                            out_of_range =
+                                if negative {
+                                    n < -922_337_203_685_477_580 or (n == -922_337_203_685_477_580 and digit > 8)
+                                } else {
+                                    n > 922_337_203_685_477_580 or (n == 922_337_203_685_477_580 and digit > 7)
+                                }
+                            if out_of_range {
+                                Err("an integer between -9223372036854775808 and 9223372036854775807")
+                            } else if negative {
+                                Ok(n * 10 - digit)
+                            } else {
+                                Ok(n * 10 + digit)
+                            }
+                        }
+                        (Err(e), _) => Err(e)
+                        (_, Err(NotADigit)) => Err(expected)
+                    },
+            )
+        }
+    }
+
+    ## Decodes `1`, `true`, `yes` or `on` as `Bool.True` and `0`, `false`, `no` or `off` as
+    ## `Bool.False`, ignoring case. For use with [Env.decode!] and [Env.list].
+    bool : Str -> Try(Bool, Str)
+    bool = |value| {
+        lowercase = Str.from_utf8_lossy(
+            List.map(Str.to_utf8(value), |byte| if byte >= 'A' and byte <= 'Z' { byte + 32 } else { byte }),
+        )
+        if List.contains(["1", "true", "yes", "on"], lowercase) {
+            Ok(Bool.True)
+        } else if List.contains(["0", "false", "no", "off"], lowercase) {
+            Ok(Bool.False)
+        } else {
+            Err("one of 1, true, yes, on, 0, false, no or off")
+        }
+    }
+
+    ## Decodes a duration like `250ms`, `30s`, `5m` or `2h` into milliseconds, so it can be
+    ## passed to [Sleep.millis!]. For use with [Env.decode!] and [Env.list].
+    duration_ms : Str -> Try(U64, Str)
+    duration_ms = |value| {
+        expected = "a duration like 250ms, 30s, 5m or 2h"
+        bytes = Str.to_utf8(value)
+        # The largest amount of each unit that still fits in a U64 of milliseconds
+        (unit_len, factor, max) =
+            if Str.ends_with(value, "ms") {
+                (2, 1, 18_446_744_073_709_551_615)
+            } else if Str.ends_with(value, "s") {
+                (1, 1_000, 18_446_744_073_709_551)
+            } else if Str.ends_with(value, "m") {
+                (1, 60_000, 307_445_734_561_825)
+            } else if Str.ends_with(value, "h") {
+                (1, 3_600_000, 5_124_095_576_030)
+            } else {
+                (0, 0, 0)
+            }
+        if factor == 0 {
+            Err(expected)
+        } else {
+            match u64(Str.from_utf8_lossy(List.drop_last(bytes, unit_len))) {
+                Ok(amount) =>
+                    if amount <= max {
+                        Ok(amount * factor)
+                    } else {
+                        Err("a duration shorter than 18446744073709551615ms")
+                    }
+                Err(_) => Err(expected)
+            }
+        }
+    }
+
+    ## Decodes a comma-separated list like `a, b, c`, decoding each item with `decoder`.
+    ## Spaces around items are ignored and an empty value is an empty list.
+    ##
+    ## ```roc
+    ## ports = Env.decode!("PORTS", Env.list(Env.u64))?
+    ## ```
+    list : (Str -> Try(a, Str)) -> (Str -> Try(List(a), Str))
+    list = |decoder| |value| {
+        trim_spaces = |bytes|
+            match bytes {
+                [' ', .. as rest] => trim_spaces(rest)
+                [.. as rest, ' '] => trim_spaces(rest)
+                _ => bytes
+            }
+        items = List.keep_if(
+            List.map(Str.split_on(value, ","), |item| Str.from_utf8_lossy(trim_spaces(Str.to_utf8(item)))),
+            |item| item != "",
+        )
+        List.fold(
+            items,
+            Ok([]),
+            |acc, item|
+                match (acc, decoder(item)) {
+                    (Ok(decoded_items), Ok(decoded)) => Ok(List.append(decoded_items, decoded))
+                    (Err(e), _) => Err(e)
+                    (_, Err(expected)) => Err("a comma-separated list of items, each ${expected}")
+                },
+        )
+    }
+
+    ## Returns all environment variables as `(name, value)` pairs, in no particular order.
+    ##
+    ## Names and values that are not valid Unicode have the invalid parts replaced with the
     ## [Unicode replacement character](https://unicode.org/glossary/#replacement_character).
+    vars! : {} => List((Str, Str))
+
+    ## Returns all environment variables as a dictionary from name to value.
     ##
-    ## Returns an empty string if the variable is not found.
-    var! : Str => Str
+    ## See [Env.vars!] for how values that are not valid Unicode are handled.
+    dict! : {} => Dict(Str, Str)
+    dict! = |{}| Dict.from_list(vars!({}))
+
+    ## Sets an environment variable for this process and the commands it runs afterwards
+    ## with [Cmd], replacing any previous value.
+    ##
+    ## Fails with `Other` if the name is empty or contains `=` or a NUL byte, or if the
+    ## value contains a NUL byte.
+    ##
+    ## ```roc
+    ## Env.set_var!("RUST_LOG", "debug")?
+    ## Cmd.exec!("cargo", ["test"])?
+    ## ```
+    set_var! : Str, Str => Try({}, [EnvErr(IOErr)])
+
+    ## Removes an environment variable from this process and the commands it runs afterwards.
+    ##
+    ## Removing a variable that is not set succeeds. Fails with `Other` for names that
+    ## [Env.set_var!] would reject.
+    remove_var! : Str => Try({}, [EnvErr(IOErr)])
+
+    ## Changes the [current working directory](https://en.wikipedia.org/wiki/Working_directory)
+    ## of this process. Relative paths used afterwards, and commands run with [Cmd], start from it.
+    ##
+    ## ```roc
+    ## Env.set_cwd!(Path.from_str("build"))?
+    ## ```
+    set_cwd! : Path => Try({}, [EnvErr(IOErr)])
+
+    ## An entry of the user database, like a line of `/etc/passwd`.
+    User : { gid : U32, home_dir : Path, name : Str, shell : Str, uid : U32 }
+
+    ## Returns the id of the user the program runs as, like `id -u`.
+    uid! : {} => U32
+
+    ## Returns the id of the group the program runs as, like `id -g`.
+    gid! : {} => U32
+
+    ## Looks up the user the program runs as, which includes their login name.
+    ##
+    ## ```roc
+    ## user = Env.current_user!({})?
+    ## Stdout.line!("Hello, ${user.name}!")
+    ## ```
+    ##
+    ## Fails with `UserNotFound` if the user id has no entry in the user database, which
+    ## happens in containers that run as an arbitrary id. [Env.uid!] works there too.
+    current_user! : {} => Try(User, [UserNotFound])
+
+    ## Looks up a user by login name.
+    user_by_name! : Str => Try(User, [UserNotFound])
+
+    ## Returns the home directory of the current user from `$HOME`, or from the user
+    ## database if that is not set.
+    home_dir! : {} => Try(Path, [NotAvailable])
+
+    ## Returns the directory for user-specific configuration files: `$XDG_CONFIG_HOME`,
+    ## or `~/.config` if that is not set.
+    ##
+    ## ```roc
+    ## config_file = Env.config_dir!({})?.join(Path.from_str("my-cli/config.toml"))
+    ## ```
+    ##
+    ## Follows the [XDG Base Directory Specification](https://specifications.freedesktop.org/basedir-spec/latest/)
+    ## on all platforms, so relative paths in the variable are ignored. Like the other base
+    ## directories, it may not exist yet. Fails with `NotAvailable` if there is no home directory.
+    config_dir! : {} => Try(Path, [NotAvailable])
+
+    ## Returns the directory for user-specific cached files, which can be deleted at any time:
+    ## `$XDG_CACHE_HOME`, or `~/.cache` if that is not set. See [Env.config_dir!].
+    cache_dir! : {} => Try(Path, [NotAvailable])
+
+    ## Returns the directory for user-specific data files: `$XDG_DATA_HOME`, or
+    ## `~/.local/share` if that is not set. See [Env.config_dir!].
+    data_dir! : {} => Try(Path, [NotAvailable])
+
+    ## Returns the directory for user-specific runtime files like sockets and lock files,
+    ## which is removed when the user logs out: `$XDG_RUNTIME_DIR`.
+    ##
+    ## There is no default, so this fails with `NotAvailable` if the variable is not set,
+    ## which is common on macOS and in containers.
+    runtime_dir! : {} => Try(Path, [NotAvailable])
 
     ## Reads the [current working directory](https://en.wikipedia.org/wiki/Working_directory)
     ## from the environment.
     ##
-    ## Returns an empty string if the cwd is unavailable.
-    cwd! : {} => Str
+    ## Returns an empty path if the cwd is unavailable, for example because it was deleted.
+    cwd! : {} => Path
+
+    ## A locale like `en-US`, with its language and region.
+    ##
+    ## `tag` is the [BCP 47](https://www.ietf.org/rfc/bcp/bcp47.html) language tag, like `en-US` or `zh-Hans-CN`,
+    ## `language` is its lowercase language code, like `en`, and `region` its uppercase region code, like `US`.
+    ## `region` is empty for locales without one, like `fr`.
+    Locale : { language : Str, region : Str, tag : Str }
+
+    ## Returns the user's preferred locales, most preferred first.
+    ##
+    ## The first of the `LC_ALL`, `LC_MESSAGES` and `LANG` environment variables that is set takes
+    ## priority over the system settings, on every platform. POSIX values like `de_DE.UTF-8` are
+    ## converted to BCP 47 tags like `de-DE`. The values `C` and `POSIX` are ignored.
+    ##
+    ## Returns an empty list if no locale is configured.
+    locales! : {} => List(Locale)
+
+    ## Returns the user's most preferred locale, see [Env.locales!].
+    ##
+    ## ```roc
+    ## language =
+    ##     match Env.locale!({}) {
+    ##         Ok(locale) => locale.language
+    ##         Err(NotAvailable) => "en"
+    ##     }
+    ## ```
+    locale! : {} => Try(Locale, [NotAvailable])
+    locale! = |{}|
+        match List.first(locales!({})) {
+            Ok(locale) => Ok(locale)
+            Err(_) => Err(NotAvailable)
+        }
+
+    ## Returns the CPU architecture and operating system the program is running on.
+    ##
+    ## ```roc
+    ## target =
+    ##     match Env.platform!({}) {
+    ##         { os: MACOS, arch: AARCH64 } => "arm64mac"
+    ##         { os: MACOS, arch: X64 } => "x64mac"
+    ##         { os: LINUX, arch: AARCH64 } => "arm64musl"
+    ##         { os: LINUX, arch: X64 } => "x64musl"
+    ##         _ => crash "unsupported platform"
+    ##     }
+    ## ```
+    ##
+    ## Architectures and operating systems without their own tag are reported as `OTHER`,
+    ## with the name Rust uses for them, like `OTHER("riscv64")` or `OTHER("freebsd")`.
+    platform! : {} => { arch : [X86, X64, ARM, AARCH64, OTHER(Str)], os : [LINUX, MACOS, WINDOWS, OTHER(Str)] }
+
+    ## Returns details about the machine the program is running on.
+    ##
+    ## - `cpu_count`: the number of CPUs this program may use, which takes CPU affinity and container limits into account.
+    ## - `hostname`: the network name of the machine, like `uname -n`.
+    ## - `kernel_release`: the kernel version, like `uname -r`, for example `6.8.0-45-generic` or `23.5.0`.
+    ## - `os_version`: the version of the OS, like `22.04` on Ubuntu 22.04 or `14.5` on macOS. Empty if unknown.
+    ## - `total_memory_bytes`: the amount of physical memory.
+    system_info! : {} => { cpu_count : U64, hostname : Str, kernel_release : Str, os_version : Str, total_memory_bytes : U64 }
 
     ## Gets the path to the currently-running executable.
     ##
@@ -25,21 +25,189 @@ File := [].{
         Other(Str),
     ]
 
+    ## An advisory lock on a file, held until it is passed to [File.unlock!] or the program exits.
+    Lock :: { id : U64 }
+
+    ## A read-only memory mapping of a file, created by [File.map!] and released with [File.unmap!].
+    Mapping :: { id : U64, len : U64 }
+
     ## Read all bytes from a file.
-    read_bytes! : Str => Try(List(U8), [FileErr(IOErr)])
+    read_bytes! : Path => Try(List(U8), [FileErr(IOErr)])
+
+    ## Map a file read-only into memory, so parts of it can be read with
+    ## [File.read_mapping!] without reading the whole file first.
+    ##
+    ## Pages are loaded from disk only when they are read, which makes this a good fit
+    ## for scanning very large files. Release the mapping with [File.unmap!], or use
+    ## [File.with_mapping!], which does it for you.
+    map! : Path => Try(Mapping, [FileErr(IOErr)])
+
+    ## The size in bytes of a mapped file, at the time it was mapped.
+    mapping_len : Mapping -> U64
+    mapping_len = |mapping| mapping.len
+
+    ## Copy up to `length` bytes starting at byte `offset` out of a mapping.
+    ##
+    ## Returns fewer bytes near the end of the file and an empty list past it. A file
+    ## that was truncated after it was mapped only gives the bytes it still has.
+    ## Fails if the mapping was already released with [File.unmap!].
+    read_mapping! : Mapping, U64, U64 => Try(List(U8), [FileErr(IOErr)])
+
+    ## Release a mapping created by [File.map!]. Releasing it again does nothing.
+    unmap! : Mapping => {}
+
+    ## Run a callback with a read-only mapping of the file at the given path.
+    ## The mapping is released when the callback returns.
+    ##
+    ## ```roc
+    ## size = File.with_mapping!(log_path, |mapping| File.mapping_len(mapping))?
+    ## ```
+    with_mapping! : Path, (Mapping => a) => Try(a, [FileErr(IOErr)])
+    with_mapping! = |path, callback!| {
+        mapping = map!(path)?
+        result = callback!(mapping)
+        unmap!(mapping)
+        Ok(result)
+    }
 
     ## Write bytes to a file, replacing any existing contents.
-    write_bytes! : Str, List(U8) => Try({}, [FileErr(IOErr)])
+    write_bytes! : Path, List(U8) => Try({}, [FileErr(IOErr)])
 
     ## Read a file's contents as a UTF-8 string.
     ##
     ## If the file contains invalid UTF-8, the invalid parts will be replaced with the
     ## [Unicode replacement character](https://unicode.org/glossary/#replacement_character).
-    read_utf8! : Str => Try(Str, [FileErr(IOErr)])
+    read_utf8! : Path => Try(Str, [FileErr(IOErr)])
 
     ## Write a UTF-8 string to a file, replacing any existing contents.
-    write_utf8! : Str, Str => Try({}, [FileErr(IOErr)])
+    write_utf8! : Path, Str => Try({}, [FileErr(IOErr)])
 
     ## Delete a file.
-    delete! : Str => Try({}, [FileErr(IOErr)])
+    delete! : Path => Try({}, [FileErr(IOErr)])
+
+    ## Copy a file's contents and permissions to another path, replacing the destination if it exists.
+    ##
+    ## When the filesystem supports it, the copy is done with a copy-on-write clone (reflink)
+    ## or inside the kernel, without passing the data through the program.
+    ## To also keep the owner and timestamps, see [File.copy_with!].
+    ##
+    ## ```roc
+    ## File.copy!(Path.from_str("config.toml"), Path.from_str("config.toml.bak"))?
+    ## ```
+    copy! : Path, Path => Try({}, [FileErr(IOErr)])
+    copy! = |from, to| copy_with!(from, to, { preserve_metadata: Bool.False })
+
+    ## Copy a file like [File.copy!], with options:
+    ##
+    ## **preserve_metadata** - Also copy the last accessed and last modified times and, when permitted, the owner and group.
+    ##
+    ## ```roc
+    ## File.copy_with!(Path.from_str("build/app"), Path.from_str("dist/app"), { preserve_metadata: Bool.True })?
+    ## ```
+    copy_with! : Path, Path, { preserve_metadata : Bool } => Try({}, [FileErr(IOErr)])
+
+    ## Move a file to another path, replacing the destination if it exists.
+    ##
+    ## If the destination is on a different filesystem, the file is copied
+    ## (including its metadata) and the original is deleted afterwards.
+    move! : Path, Path => Try({}, [FileErr(IOErr)])
+
+    ## Set the Unix permission bits of a file, e.g. `0o755` to make a script executable.
+    ##
+    ## ```roc
+    ## File.set_permissions!(Path.from_str("install.sh"), 0o755)?
+    ## ```
+    set_permissions! : Path, U32 => Try({}, [FileErr(IOErr)])
+
+    ## Change the owner and group of a file, given as numeric user and group ids.
+    ##
+    ## This usually requires elevated privileges.
+    chown! : Path, U32, U32 => Try({}, [FileErr(IOErr)])
+
+    ## Set the last accessed and last modified times of a file.
+    ##
+    ## Both times are nanoseconds since the Unix epoch, the same unit returned by [Utc.now!].
+    ##
+    ## ```roc
+    ## now = Utc.now!({})
+    ## File.set_times!(Path.from_str("output.txt"), now, now)?
+    ## ```
+    set_times! : Path, U128, U128 => Try({}, [FileErr(IOErr)])
+
+    ## Take an exclusive advisory lock on a file, creating the file if it does not exist.
+    ##
+    ## Waits until no other process holds a lock on the file. Advisory locks only coordinate
+    ## programs that lock the same file; they do not stop anyone from reading or writing it.
+    ##
+    ## If this program already holds a lock on the file, for example in a nested
+    ## [File.with_lock!], this fails right away instead of waiting for itself forever.
+    ##
+    ## Prefer [File.with_lock!], which releases the lock for you.
+    lock_exclusive! : Path => Try(Lock, [FileErr(IOErr)])
+
+    ## Take a shared advisory lock on a file, creating the file if it does not exist.
+    ##
+    ## Any number of processes can hold a shared lock at the same time, but waits
+    ## while another process holds an exclusive lock. Fails right away if this program
+    ## holds an exclusive lock on the file itself.
+    lock_shared! : Path => Try(Lock, [FileErr(IOErr)])
+
+    ## Like [File.lock_exclusive!], but fails with `WouldBlock` instead of waiting
+    ## when another process already holds a lock on the file. A lock held by this
+    ## program fails with `FileErr` instead.
+    try_lock! : Path => Try(Lock, [FileErr(IOErr), WouldBlock])
+
+    ## Like [File.lock_shared!], but fails with `WouldBlock` instead of waiting
+    ## when another process holds an exclusive lock on the file. An exclusive lock
+    ## held by this program fails with `FileErr` instead.
+    try_lock_shared! : Path => Try(Lock, [FileErr(IOErr), WouldBlock])
+
+    ## Release a lock taken with [File.lock_exclusive!], [File.lock_shared!],
+    ## [File.try_lock!] or [File.try_lock_shared!]. Releasing it again does nothing.
+    unlock! : Lock => {}
+
+    ## Run a callback while holding an exclusive lock on the file at the given path.
+    ## The lock is released when the callback returns, whether it succeeded or not.
+    ##
+    ## ```roc
+    ## File.with_lock!(Path.from_str("cache/.lock"), |{}| rebuild_cache!({}))?
+    ## ```
+    with_lock! : Path, ({} => a) => Try(a, [FileErr(IOErr)])
+    with_lock! = |path, callback!| {
+        lock = lock_exclusive!(path)?
+        result = callback!({})
+        unlock!(lock)
+        Ok(result)
+    }
+
+    ## Creates a new, empty file with a unique name in the system temp directory
+    ## (`$TMPDIR`, or `/tmp` if that is not set) and returns its path.
+    ##
+    ## The name starts with `prefix`, followed by random characters. The file is created
+    ## exclusively (`O_EXCL`), so no other program can get the same file, and only the
+    ## current user can read or write it.
+    ##
+    ## A `prefix` containing `/` or `..` fails with an error, so the file always ends up
+    ## directly in the temp directory.
+    ##
+    ## The file is not deleted automatically; use [File.with_temp!] for that.
+    create_temp! : Str => Try(Path, [FileErr(IOErr)])
+
+    ## Creates a temporary file like [File.create_temp!], passes its path to the callback,
+    ## and deletes the file when the callback returns, whether it succeeded or not.
+    ##
+    ## ```roc
+    ## File.with_temp!("report-", |path| {
+    ##     File.write_utf8!(path, report)?
+    ##     Cmd.new("lpr").arg_path(path).exec_cmd!()
+    ## })?
+    ## ```
+    with_temp! : Str, (Path => a) => Try(a, [FileErr(IOErr)])
+    with_temp! = |prefix, callback!| {
+        path = create_temp!(prefix)?
+        result = callback!(path)
+        # The callback may already have removed or moved the file
+        _ = delete!(path)
+        Ok(result)
+    }
 }
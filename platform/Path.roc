@@ -1,4 +1,4 @@
-Path := [].{
+Path :: { bytes : List(U8) }.{
     ## **NotFound** - An entity was not found, often a file.
     ##
     ## **PermissionDenied** - The operation lacked the necessary privileges to complete.
@@ -25,21 +25,539 @@ Path := [].{
         Other(Str),
     ]
 
+    ## Create a path from a string.
+    ##
+    ## ```roc
+    ## path = Path.from_str("examples/hello.roc")
+    ## ```
+    from_str : Str -> Path
+    from_str = |str| { bytes: Str.to_utf8(str) }
+
+    ## Create a path from raw bytes.
+    ##
+    ## On Unix a path can be any sequence of bytes except `0`, so this can represent
+    ## filenames that are not valid UTF-8.
+    from_bytes : List(U8) -> Path
+    from_bytes = |bytes| { bytes }
+
+    ## The raw bytes of the path, exactly as the operating system sees them.
+    to_bytes : Path -> List(U8)
+    to_bytes = |path| path.bytes
+
+    ## Convert a path to a string, failing if it is not valid UTF-8.
+    ##
+    ## To always get a string, for example for printing, use [Path.display].
+    to_str : Path -> Try(Str, [BadUtf8])
+    to_str = |path|
+        match Str.from_utf8(path.bytes) {
+            Ok(str) => Ok(str)
+            Err(_) => Err(BadUtf8)
+        }
+
+    ## Convert a path to a string for display purposes.
+    ##
+    ## Invalid UTF-8 is replaced with the
+    ## [Unicode replacement character](https://unicode.org/glossary/#replacement_character),
+    ## so the result may not point at the same file.
+    display : Path -> Str
+    display = |path| Str.from_utf8_lossy(path.bytes)
+
+    ## Returns `Bool.True` if the path starts at the root directory, e.g. `/usr/bin`.
+    ##
+    ## The functions below only look at the bytes of the path and never touch the filesystem,
+    ## so they work the same for paths that are not valid UTF-8.
+    is_absolute : Path -> Bool
+    is_absolute = |path|
+        match path.bytes {
+            ['/', ..] => Bool.True
+            _ => Bool.False
+        }
+
+    ## Joins two paths with a `/` separator.
+    ##
+    ## If `child` is absolute, it replaces `base`, just like `cd` would.
+    ##
+    ## ```roc
+    ## Path.from_str("src").join(Path.from_str("main.roc")) # src/main.roc
+    ## Path.from_str("src").join(Path.from_str("/etc/hosts")) # /etc/hosts
+    ## ```
+    join : Path, Path -> Path
+    join = |base, child|
+        if is_absolute(child) or List.is_empty(base.bytes) {
+            child
+        } else {
+            match List.last(base.bytes) {
+                Ok('/') => from_bytes(List.concat(base.bytes, child.bytes))
+                _ => from_bytes(List.concat(List.append(base.bytes, '/'), child.bytes))
+            }
+        }
+
+    ## Splits a path into its components.
+    ##
+    ## An absolute path starts with a `/` component. Repeated separators
+    ## and `.` components are skipped; `..` components are kept.
+    ##
+    ## ```roc
+    ## Path.from_str("/usr//lib/./roc").components() # /, usr, lib, roc
+    ## ```
+    components : Path -> List(Path)
+    components = |path| {
+        split = List.fold(
+            path.bytes,
+            { parts: [], current: [] },
+            |state, byte|
+                if byte == '/' {
+                    { parts: List.append(state.parts, state.current), current: [] }
+                } else {
+                    { ..state, current: List.append(state.current, byte) }
+                },
+        )
+        parts =
+            List.append(split.parts, split.current)
+                .keep_if(|part| part != [] and part != ['.'])
+                .map(from_bytes)
+        if is_absolute(path) {
+            List.prepend(parts, from_str("/"))
+        } else {
+            parts
+        }
+    }
+
+    ## Builds a path from components, the inverse of [Path.components].
+    from_components : List(Path) -> Path
+    from_components = |parts| {
+        bytes = List.fold(
+            parts,
+            [],
+            |acc, part|
+                if List.is_empty(acc) or acc == ['/'] {
+                    List.concat(acc, part.bytes)
+                } else {
+                    List.concat(List.append(acc, '/'), part.bytes)
+                },
+        )
+        from_bytes(bytes)
+    }
+
+    ## Returns the path without its last component.
+    ##
+    ## ```roc
+    ## Path.parent(Path.from_str("src/utils/str.roc")) # Ok(src/utils)
+    ## Path.parent(Path.from_str("main.roc")) # Ok(empty path)
+    ## Path.parent(Path.from_str("/")) # Err(NoParent)
+    ## ```
+    parent : Path -> Try(Path, [NoParent])
+    parent = |path| {
+        parts = components(path)
+        match parts.map(to_bytes) {
+            [] => Err(NoParent)
+            [['/']] => Err(NoParent)
+            _ => Ok(from_components(List.drop_last(parts, 1)))
+        }
+    }
+
+    ## Returns the last component of the path, unless it is the root or `..`.
+    ##
+    ## ```roc
+    ## Path.file_name(Path.from_str("src/main.roc")) # Ok(main.roc)
+    ## ```
+    file_name : Path -> Try(Path, [NoFileName])
+    file_name = |path|
+        match List.last(components(path)) {
+            Ok(name) =>
+                match name.bytes {
+                    ['/'] => Err(NoFileName)
+                    ['.', '.'] => Err(NoFileName)
+                    _ => Ok(name)
+                }
+            Err(_) => Err(NoFileName)
+        }
+
+    ## Returns the file name without its extension.
+    ##
+    ## Only the last extension is removed, and a leading `.` (as in `.bashrc`) is not
+    ## treated as the start of an extension.
+    ##
+    ## ```roc
+    ## Path.stem(Path.from_str("dist/app.tar.gz")) # Ok(app.tar)
+    ## ```
+    stem : Path -> Try(Path, [NoFileName])
+    stem = |path|
+        match file_name(path) {
+            Err(NoFileName) => Err(NoFileName)
+            Ok(name) =>
+                match List.find_last_index(name.bytes, |byte| byte == '.') {
+                    Ok(0) => Ok(name)
+                    Ok(dot) => Ok(from_bytes(List.take_first(name.bytes, dot)))
+                    Err(_) => Ok(name)
+                }
+        }
+
+    ## Returns the extension of the file name, without the `.`.
+    ##
+    ## ```roc
+    ## Path.extension(Path.from_str("dist/app.tar.gz")) # Ok(gz)
+    ## Path.extension(Path.from_str(".bashrc")) # Err(NoExtension)
+    ## ```
+    extension : Path -> Try(Path, [NoExtension])
+    extension = |path|
+        match file_name(path) {
+            Err(NoFileName) => Err(NoExtension)
+            Ok(name) =>
+                match List.find_last_index(name.bytes, |byte| byte == '.') {
+                    Ok(0) => Err(NoExtension)
+                    Ok(dot) => Ok(from_bytes(List.drop_first(name.bytes, dot + 1)))
+                    Err(_) => Err(NoExtension)
+                }
+        }
+
+    ## Replaces the extension of the file name, or adds one if there is none.
+    ## An empty extension removes the current one.
+    ##
+    ## The result is built from [Path.components], so repeated separators and `.` components are dropped.
+    ##
+    ## ```roc
+    ## Path.with_extension(Path.from_str("notes/todo.md"), "html") # notes/todo.html
+    ## ```
+    with_extension : Path, Str -> Path
+    with_extension = |path, ext|
+        match stem(path) {
+            Err(NoFileName) => path
+            Ok(file_stem) => {
+                name =
+                    if Str.is_empty(ext) {
+                        file_stem
+                    } else {
+                        from_bytes(List.concat(List.append(file_stem.bytes, '.'), Str.to_utf8(ext)))
+                    }
+                from_components(List.append(List.drop_last(components(path), 1), name))
+            }
+        }
+
+    ## Resolves `.` and `..` components without touching the filesystem.
+    ##
+    ## Unlike [Path.canonicalize!], symbolic links are not followed, so `a/link/..`
+    ## becomes `a` even if `link` points somewhere else.
+    ##
+    ## ```roc
+    ## Path.normalize(Path.from_str("src/./utils/../main.roc")) # src/main.roc
+    ## Path.normalize(Path.from_str("../lib")) # ../lib
+    ## ```
+    normalize : Path -> Path
+    normalize = |path| {
+        absolute = is_absolute(path)
+        resolved = List.fold(
+            components(path),
+            [],
+            |acc, part|
+                match part.bytes {
+                    ['/'] => acc
+                    ['.', '.'] =>
+                        match List.last(acc) {
+                            Ok(last) => if last.bytes == ['.', '.'] { List.append(acc, part) } else { List.drop_last(acc, 1) }
+                            # `..` at the root stays at the root
+                            Err(_) => if absolute { acc } else { List.append(acc, part) }
+                        }
+                    _ => List.append(acc, part)
+                },
+        )
+        parts = if absolute { List.prepend(resolved, from_str("/")) } else { resolved }
+        if List.is_empty(parts) {
+            from_str(".")
+        } else {
+            from_components(parts)
+        }
+    }
+
+    ## Returns a relative path that leads from `base` to `path`, using `..` where needed.
+    ##
+    ## Both paths are normalized first. Fails if one path is absolute and the other is not,
+    ## or if `base` climbs out of the directory the relative paths start from.
+    ##
+    ## ```roc
+    ## Path.relative_to(Path.from_str("/srv/app/static/logo.png"), Path.from_str("/srv/app")) # Ok(static/logo.png)
+    ## Path.relative_to(Path.from_str("docs/index.md"), Path.from_str("src/utils")) # Ok(../../docs/index.md)
+    ## ```
+    relative_to : Path, Path -> Try(Path, [NotRelative])
+    relative_to = |path, base| {
+        path_parts = components(normalize(path)).map(to_bytes)
+        base_parts = components(normalize(base)).map(to_bytes)
+
+        count_common = |a, b|
+            match (List.first(a), List.first(b)) {
+                (Ok(x), Ok(y)) =>
+                    if x == y {
+                        1 + count_common(List.drop_first(a, 1), List.drop_first(b, 1))
+                    } else {
+                        0
+                    }
+                _ => 0
+            }
+
+        common = count_common(path_parts, base_parts)
+        base_rest = List.drop_first(base_parts, common)
+
+        if is_absolute(path) != is_absolute(base) or List.contains(base_rest, ['.', '.']) {
+            Err(NotRelative)
+        } else {
+            parts = List.concat(List.repeat(['.', '.'], List.len(base_rest)), List.drop_first(path_parts, common))
+            if List.is_empty(parts) {
+                Ok(from_str("."))
+            } else {
+                Ok(from_components(parts.map(from_bytes)))
+            }
+        }
+    }
+
+    ## Checks if a path matches a glob pattern, without touching the filesystem.
+    ##
+    ## Uses the same pattern syntax as [Dir.glob!]: `*`, `?`, `**`, `[a-z]`, `[!a-z]` and `{a,b}`.
+    ## Both the path and the pattern are compared component by component, so `src/*.roc`
+    ## matches `./src//main.roc` but not `src/utils/str.roc`. Wildcards match whole UTF-8
+    ## characters, so `caf?` matches `café`.
+    ##
+    ## ```roc
+    ## Path.matches_glob(Path.from_str("src/utils/str.roc"), "src/**/*.{roc,md}") # Bool.True
+    ## ```
+    matches_glob : Path, Str -> Bool
+    matches_glob = |path, pattern| {
+        path_parts = components(path).map(to_bytes)
+        List.any(
+            glob_expand_braces(Str.to_utf8(pattern)),
+            |alternative|
+                glob_match(
+                    components(from_bytes(alternative)).map(to_bytes),
+                    path_parts,
+                    |part| part == ['*', '*'],
+                    glob_match_name,
+                ),
+        )
+    }
+
+    # Expands the first `{a,b}` group, then the rest of the pattern recursively
+    glob_expand_braces = |pattern| {
+        scan = List.fold(
+            pattern,
+            { depth: 0, closed: Bool.False, prefix: [], current: [], alternatives: [], suffix: [] },
+            |state, byte|
+                if state.closed {
+                    { ..state, suffix: List.append(state.suffix, byte) }
+                } else if state.depth == 0 {
+                    if byte == '{' {
+                        { ..state, depth: 1 }
+                    } else {
+                        { ..state, prefix: List.append(state.prefix, byte) }
+                    }
+                } else if byte == '}' and state.depth == 1 {
+                    { ..state, depth: 0, closed: Bool.True, alternatives: List.append(state.alternatives, state.current) }
+                } else if byte == ',' and state.depth == 1 {
+                    { ..state, current: [], alternatives: List.append(state.alternatives, state.current) }
+                } else if byte == '{' {
+                    { ..state, depth: state.depth + 1, current: List.append(state.current, byte) }
+                } else if byte == '}' {
+                    { ..state, depth: state.depth - 1, current: List.append(state.current, byte) }
+                } else {
+                    { ..state, current: List.append(state.current, byte) }
+                },
+        )
+
+        # A `{` without a matching `}` is a literal character
+        if scan.closed {
+            List.join(
+                List.map(
+                    scan.alternatives,
+                    |alternative| glob_expand_braces(List.concat(List.concat(scan.prefix, alternative), scan.suffix)),
+                ),
+            )
+        } else {
+            [pattern]
+        }
+    }
+
+    # Wildcard matching that remembers only the last star: on a mismatch, that star takes
+    # one more item and matching resumes right after it. Unlike trying every split, this
+    # never takes more than (pattern length * items) steps.
+    #
+    # Used for both `*` within a name and `**` across components.
+    glob_match = |pattern, items, is_star, matches_one|
+        glob_match_from(pattern, items, is_star, matches_one, 0, 0, NoStar)
+
+    glob_match_from = |pattern, items, is_star, matches_one, pattern_index, item_index, star|
+        match (List.get(pattern, pattern_index), List.get(items, item_index)) {
+            # Once all items are used up, only stars may be left
+            (_, Err(_)) => List.all(List.drop_first(pattern, pattern_index), is_star)
+            (Ok(token), Ok(item)) =>
+                if is_star(token) {
+                    glob_match_from(
+                        pattern,
+                        items,
+                        is_star,
+                        matches_one,
+                        pattern_index + 1,
+                        item_index,
+                        Star({ pattern_index: pattern_index + 1, item_index }),
+                    )
+                } else if matches_one(token, item) {
+                    glob_match_from(pattern, items, is_star, matches_one, pattern_index + 1, item_index + 1, star)
+                } else {
+                    glob_backtrack(pattern, items, is_star, matches_one, star)
+                }
+            (Err(_), Ok(_)) => glob_backtrack(pattern, items, is_star, matches_one, star)
+        }
+
+    glob_backtrack = |pattern, items, is_star, matches_one, star|
+        match star {
+            NoStar => Bool.False
+            Star(resume) => {
+                next = { ..resume, item_index: resume.item_index + 1 }
+                glob_match_from(pattern, items, is_star, matches_one, next.pattern_index, next.item_index, Star(next))
+            }
+        }
+
+    # Matches one pattern component against one path component, character by character
+    glob_match_name = |pattern, name|
+        glob_match(
+            glob_tokens(glob_chars(pattern)),
+            glob_chars(name),
+            |token|
+                match token {
+                    AnyChars => Bool.True
+                    _ => Bool.False
+                },
+            glob_token_matches,
+        )
+
+    # Splits bytes into UTF-8 encoded characters. Bytes that are not valid UTF-8 stay on their own.
+    glob_chars = |bytes|
+        List.fold(
+            bytes,
+            [],
+            |chars, byte|
+                # A continuation byte belongs to the character before it
+                if byte >= 0x80 and byte < 0xC0 {
+                    match List.last(chars) {
+                        Ok(last) => List.append(List.drop_last(chars, 1), List.append(last, byte))
+                        Err(_) => [[byte]]
+                    }
+                } else {
+                    List.append(chars, [byte])
+                },
+        )
+
+    # Turns the characters of a pattern component into `*`, `?`, `[...]` and literal tokens
+    glob_tokens = |chars|
+        match chars {
+            [] => []
+            [['*'], .. as rest] => List.prepend(glob_tokens(rest), AnyChars)
+            [['?'], .. as rest] => List.prepend(glob_tokens(rest), AnyChar)
+            [['['], .. as rest] => {
+                (negated, class) =
+                    match rest {
+                        [['!'], .. as class_rest] => (Bool.True, class_rest)
+                        [['^'], .. as class_rest] => (Bool.True, class_rest)
+                        _ => (Bool.False, rest)
+                    }
+
+                # A `]` right after the `[` is part of the class
+                match List.find_first_index(List.drop_first(class, 1), |char| char == [']']) {
+                    Ok(index) =>
+                        List.prepend(
+                            glob_tokens(List.drop_first(class, index + 2)),
+                            Class({ negated, members: List.take_first(class, index + 1) }),
+                        )
+                    # No closing `]`, so the `[` is a literal character
+                    Err(_) => List.prepend(glob_tokens(rest), Literal(['[']))
+                }
+            }
+            [char, .. as rest] => List.prepend(glob_tokens(rest), Literal(char))
+        }
+
+    glob_token_matches = |token, char|
+        match token {
+            AnyChars => Bool.True
+            AnyChar => Bool.True
+            Literal(expected) => char == expected
+            Class({ negated, members }) => glob_in_class(members, char) != negated
+        }
+
+    glob_in_class = |members, char|
+        match members {
+            [low, ['-'], high, .. as rest] =>
+                (glob_char_at_most(low, char) and glob_char_at_most(char, high)) or glob_in_class(rest, char)
+            [member, .. as rest] => member == char or glob_in_class(rest, char)
+            [] => Bool.False
+        }
+
+    # UTF-8 keeps the order of code points, so comparing the bytes compares the characters
+    glob_char_at_most = |char, limit|
+        match (char, limit) {
+            ([], _) => Bool.True
+            (_, []) => Bool.False
+            ([byte, .. as char_rest], [limit_byte, .. as limit_rest]) =>
+                if byte == limit_byte {
+                    glob_char_at_most(char_rest, limit_rest)
+                } else {
+                    byte < limit_byte
+                }
+        }
+
     ## Returns `Bool.true` if the path exists on disk and is pointing at a regular file.
     ##
     ## This function will traverse symbolic links to query information about the
     ## destination file. In case of broken symbolic links this will return `Bool.false`.
-    is_file! : Str => Try(Bool, [PathErr(IOErr)])
+    is_file! : Path => Try(Bool, [PathErr(IOErr)])
 
     ## Returns `Bool.true` if the path exists on disk and is pointing at a directory.
     ##
     ## This function will traverse symbolic links to query information about the
     ## destination file. In case of broken symbolic links this will return `Bool.false`.
-    is_dir! : Str => Try(Bool, [PathErr(IOErr)])
+    is_dir! : Path => Try(Bool, [PathErr(IOErr)])
 
     ## Returns `Bool.true` if the path exists on disk and is pointing at a symbolic link.
     ##
     ## This function will not traverse symbolic links - it checks whether the path
     ## itself is a symlink.
-    is_sym_link! : Str => Try(Bool, [PathErr(IOErr)])
+    is_sym_link! : Path => Try(Bool, [PathErr(IOErr)])
+
+    ## Creates a symbolic link at `link` that points to `target`.
+    ##
+    ## The target does not need to exist. A relative target is resolved
+    ## relative to the directory containing the link, not the current directory.
+    ##
+    ## ```roc
+    ## Path.symlink!(Path.from_str("app-1.2.0"), Path.from_str("releases/current"))?
+    ## ```
+    symlink! : Path, Path => Try({}, [PathErr(IOErr)])
+
+    ## Returns the path a symbolic link points to, exactly as it was stored in the link.
+    ##
+    ## Fails if the path is not a symbolic link.
+    read_link! : Path => Try(Path, [PathErr(IOErr)])
+
+    ## Creates a new hard link at `link` for the existing file at `original`.
+    ##
+    ## Both paths will refer to the same file contents; both must be on the same filesystem.
+    hard_link! : Path, Path => Try({}, [PathErr(IOErr)])
+
+    ## Returns the absolute form of the path with all symbolic links resolved
+    ## and all `.` and `..` components removed.
+    ##
+    ## Fails if the path does not exist.
+    canonicalize! : Path => Try(Path, [PathErr(IOErr)])
+
+    ## Returns the size and free space of the filesystem that contains the path.
+    ##
+    ## `free_bytes` includes space reserved for the root user, `available_bytes` is what
+    ## the current user can actually write. `type` is the filesystem type, like `ext4`,
+    ## `tmpfs` or `apfs`. Unknown Linux filesystems are reported by their magic number, like `0x1234`.
+    ##
+    ## ```roc
+    ## stats = Path.filesystem_stats!(Path.from_str("dist"))?
+    ## if stats.available_bytes < artifact_size {
+    ##     Err(NotEnoughSpace)
+    ## } else {
+    ##     File.write_bytes!(Path.from_str("dist/app.tar"), artifact)
+    ## }
+    ## ```
+    filesystem_stats! : Path => Try({ available_bytes : U64, free_bytes : U64, total_bytes : U64, type : Str }, [PathErr(IOErr)])
 }
@@ -0,0 +1,58 @@
+Process := [].{
+    ## **NotFound** - An entity was not found, often a file.
+    ##
+    ## **PermissionDenied** - The operation lacked the necessary privileges to complete.
+    ##
+    ## **BrokenPipe** - The operation failed because a pipe was closed.
+    ##
+    ## **AlreadyExists** - An entity already exists, often a file.
+    ##
+    ## **Interrupted** - This operation was interrupted. Interrupted operations can typically be retried.
+    ##
+    ## **Unsupported** - This operation is unsupported on this platform. This means that the operation can never succeed.
+    ##
+    ## **OutOfMemory** - An operation could not be completed, because it failed to allocate enough memory.
+    ##
+    ## **Other** - A custom error that does not fall under any other I/O error kind.
+    IOErr := [
+        NotFound,
+        PermissionDenied,
+        BrokenPipe,
+        AlreadyExists,
+        Interrupted,
+        Unsupported,
+        OutOfMemory,
+        Other(Str),
+    ]
+
+    ## Returns the process ID of the running program.
+    pid! : {} => U32
+
+    ## Returns the process ID of the process that started the running program, usually a shell.
+    parent_pid! : {} => U32
+
+    ## Exits the program immediately with the given exit code, without returning from `main!`.
+    ##
+    ## Anything written to stdout or stderr is flushed first.
+    ##
+    ## ```roc
+    ## if List.is_empty(args) {
+    ##     Stderr.line!("Usage: my-app <file>")
+    ##     Process.exit!(2)
+    ## }
+    ## ```
+    exit! : I32 => {}
+
+    ## Aborts the program immediately, like a crash.
+    ##
+    ## Nothing is flushed and the exit status indicates the program was killed by `SIGABRT`.
+    abort! : {} => {}
+
+    ## Returns the CPU time used by the running program so far, and the most memory it has
+    ## used at once.
+    ##
+    ## `user_cpu_nanos` is time spent running the program itself, `system_cpu_nanos` is time
+    ## the operating system spent working on its behalf, for example reading files.
+    ## `max_rss_bytes` is the peak resident set size, the most physical memory in use at once.
+    resource_usage! : {} => Try({ max_rss_bytes : U64, system_cpu_nanos : U64, user_cpu_nanos : U64 }, [ProcessErr(IOErr)])
+}
@@ -0,0 +1,81 @@
+Signal := [].{
+    ## **NotFound** - An entity was not found, often a file.
+    ##
+    ## **PermissionDenied** - The operation lacked the necessary privileges to complete.
+    ##
+    ## **BrokenPipe** - The operation failed because a pipe was closed.
+    ##
+    ## **AlreadyExists** - An entity already exists, often a file.
+    ##
+    ## **Interrupted** - This operation was interrupted. Interrupted operations can typically be retried.
+    ##
+    ## **Unsupported** - This operation is unsupported on this platform. This means that the operation can never succeed.
+    ##
+    ## **OutOfMemory** - An operation could not be completed, because it failed to allocate enough memory.
+    ##
+    ## **Other** - A custom error that does not fall under any other I/O error kind.
+    IOErr := [
+        NotFound,
+        PermissionDenied,
+        BrokenPipe,
+        AlreadyExists,
+        Interrupted,
+        Unsupported,
+        OutOfMemory,
+        Other(Str),
+    ]
+
+    ## The signals a program can listen for and send.
+    ##
+    ## **Hangup** - `SIGHUP`, sent when the terminal closes. Long-running programs often use it to reload their configuration.
+    ##
+    ## **Interrupt** - `SIGINT`, sent when the user presses Ctrl-C.
+    ##
+    ## **Terminate** - `SIGTERM`, a polite request to shut down, sent by `kill` and service managers.
+    ##
+    ## **User1** - `SIGUSR1`, has no fixed meaning, programs decide what it does.
+    Kind : [Hangup, Interrupt, Terminate, User1]
+
+    ## Starts catching the given signals instead of letting them end the program.
+    ##
+    ## Caught signals are kept until they are read with [Signal.poll!] or [Signal.wait!].
+    ## Once a program listens for `Interrupt`, Ctrl-C no longer stops it on its own,
+    ## so make sure to check for it.
+    ##
+    ## A caught signal interrupts a program that is blocked waiting: [Watch.wait!] and
+    ## [Stdin.line!] fail with `Interrupted`, and [Sleep.millis!] returns early. Check
+    ## [Signal.poll!] afterwards to see which signal arrived. Other blocking calls, like
+    ## running a command, finish first.
+    ##
+    ## ```roc
+    ## Signal.listen!([Interrupt, Terminate])?
+    ## ```
+    listen! : List(Kind) => Try({}, [SignalErr(IOErr)])
+
+    ## Returns the signals that arrived since the previous [Signal.poll!] or [Signal.wait!],
+    ## oldest first, without waiting. Returns an empty list if none arrived.
+    ##
+    ## ```roc
+    ## signals = Signal.poll!({})?
+    ## if List.contains(signals, Interrupt) {
+    ##     File.delete!(temp_file)?
+    ##     Err(Exit(130))
+    ## } else {
+    ##     process_next!(work)
+    ## }
+    ## ```
+    poll! : {} => Try(List(Kind), [SignalErr(IOErr)])
+
+    ## Waits until one of the signals passed to [Signal.listen!] arrives, and returns it.
+    ##
+    ## Fails if the program is not listening for any signals, for example after only calling
+    ## `Signal.listen!([])`, because it would wait forever.
+    wait! : {} => Try(Kind, [SignalErr(IOErr)])
+
+    ## Sends a signal to the process with the given process ID.
+    ##
+    ## ```roc
+    ## Signal.send!(server_pid, Hangup)?
+    ## ```
+    send! : U32, Kind => Try({}, [SignalErr(IOErr)])
+}
@@ -1,4 +1,6 @@
 Sleep := [].{
     ## Sleep for the specified number of milliseconds.
+    ##
+    ## Returns early if a signal caught by [Signal.listen!] arrives.
     millis! : U64 => {}
 }
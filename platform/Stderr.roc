@@ -39,9 +39,9 @@ Stderr := [].{
     ## > To write to `stderr` with a newline at the end, see [Stderr.line!].
     write! : Str => {}
 
-    # ## Write the given bytes to [standard error](https://en.wikipedia.org/wiki/Standard_streams#Standard_error_(stderr)).
-    # ##
-    # ## Most terminals will not actually display content that are written to them until they receive a newline,
-    # ## so this may appear to do nothing until you write a newline!
-    # write_bytes! : List(U8) => {}
+    ## Write the given bytes to [standard error](https://en.wikipedia.org/wiki/Standard_streams#Standard_error_(stderr)).
+    ##
+    ## Most terminals will not actually display content that are written to them until they receive a newline,
+    ## so this may appear to do nothing until you write a newline!
+    write_bytes! : List(U8) => Try({}, [StderrErr(IOErr)])
 }
@@ -31,17 +31,20 @@ Stdin := [].{
     ## (e.g. because the user pressed Enter in the terminal), so using it can result in the appearance of the
     ## program having gotten stuck. It's often helpful to print a prompt first, so
     ## the user knows it's necessary to enter something before the program will continue.
-    line! : {} => Str
+    ##
+    ## The line ending (`\n` or `\r\n`) is not included in the returned string.
+    ## Fails with `EndOfFile` once there is nothing left to read, for example after
+    ## the user pressed Ctrl-D or the end of piped in input was reached.
+    ## Fails with `Interrupted` if a signal caught by [Signal.listen!] arrives while waiting.
+    line! : {} => Try(Str, [EndOfFile, StdinErr(IOErr)])
 
-    # ## Read bytes from [standard input](https://en.wikipedia.org/wiki/Standard_streams#Standard_input_(stdin)).
-    # ## This function can read no more than 16,384 bytes at a time. Use [read_to_end!] if you need more.
-    # ##
-    # ## > This is typically used in combintation with [Tty.enable_raw_mode!],
-    # ## which disables defaults terminal bevahiour and allows reading input
-    # ## without buffering until Enter key is pressed.
-    # bytes! : {} => List(U8)
+    ## Read bytes from [standard input](https://en.wikipedia.org/wiki/Standard_streams#Standard_input_(stdin)).
+    ## This function can read no more than 16,384 bytes at a time. Use [read_to_end!] if you need more.
+    ##
+    ## Returns an empty list once `stdin` has reached [EOF](https://en.wikipedia.org/wiki/End-of-file).
+    bytes! : {} => Try(List(U8), [StdinErr(IOErr)])
 
-    # ## Read all bytes from [standard input](https://en.wikipedia.org/wiki/Standard_streams#Standard_input_(stdin))
-    # ## until [EOF](https://en.wikipedia.org/wiki/End-of-file) in this source.
-    # read_to_end! : {} => List(U8)
+    ## Read all bytes from [standard input](https://en.wikipedia.org/wiki/Standard_streams#Standard_input_(stdin))
+    ## until [EOF](https://en.wikipedia.org/wiki/End-of-file) in this source.
+    read_to_end! : {} => Try(List(U8), [StdinErr(IOErr)])
 }
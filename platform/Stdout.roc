@@ -39,9 +39,9 @@ Stdout := [].{
     ## > To write to `stdout` with a newline at the end, see [Stdout.line!].
     write! : Str => {}
 
-    # ## Write the given bytes to [standard output](https://en.wikipedia.org/wiki/Standard_streams#Standard_output_(stdout)).
-    # ##
-    # ## Note that many terminals will not actually display content that is written to them until they receive a newline,
-    # ## so this may appear to do nothing until you write a newline!
-    # write_bytes! : List(U8) => {}
+    ## Write the given bytes to [standard output](https://en.wikipedia.org/wiki/Standard_streams#Standard_output_(stdout)).
+    ##
+    ## Note that many terminals will not actually display content that is written to them until they receive a newline,
+    ## so this may appear to do nothing until you write a newline!
+    write_bytes! : List(U8) => Try({}, [StdoutErr(IOErr)])
 }
@@ -0,0 +1,94 @@
+Watch := [].{
+    ## **NotFound** - An entity was not found, often a file.
+    ##
+    ## **PermissionDenied** - The operation lacked the necessary privileges to complete.
+    ##
+    ## **BrokenPipe** - The operation failed because a pipe was closed.
+    ##
+    ## **AlreadyExists** - An entity already exists, often a file.
+    ##
+    ## **Interrupted** - This operation was interrupted. Interrupted operations can typically be retried.
+    ##
+    ## **Unsupported** - This operation is unsupported on this platform. Watching is currently only supported on Linux.
+    ##
+    ## **OutOfMemory** - An operation could not be completed, because it failed to allocate enough memory.
+    ##
+    ## **Other** - A custom error that does not fall under any other I/O error kind.
+    IOErr := [
+        NotFound,
+        PermissionDenied,
+        BrokenPipe,
+        AlreadyExists,
+        Interrupted,
+        Unsupported,
+        OutOfMemory,
+        Other(Str),
+    ]
+
+    ## Watches a file or directory for changes until it is passed to [Watch.stop!] or the program exits.
+    Watcher :: { id : U64 }
+
+    ## A change to a path inside the watched directory.
+    ##
+    ## `Renamed` holds the old path, `path` is the new one. A path that is moved into the
+    ## watched directory from elsewhere is reported as `Created`, and one that is moved
+    ## out as `Deleted`.
+    ##
+    ## `Overflow` means too many changes happened at once and some of them were lost. Its
+    ## `path` is the watched path, and anything below it may have changed, so rescan it.
+    ##
+    ## When a watched file is replaced by renaming another file over it, like many editors
+    ## do when saving, the new file is watched from then on and the change is `Modified`.
+    Event : { kind : [Created, Deleted, Modified, Overflow, Renamed(Path)], path : Path }
+
+    ## Starts watching a file or directory for changes.
+    ##
+    ## With `recursive`, everything below the directory is watched too, including
+    ## directories that are created later.
+    ##
+    ## ```roc
+    ## watcher = Watch.start!(Path.from_str("src"), { recursive: Bool.True })?
+    ## ```
+    ##
+    ## Uses inotify, so this fails with `Unsupported` on platforms other than Linux.
+    start! : Path, { recursive : Bool } => Try(Watcher, [WatchErr(IOErr)])
+
+    ## Returns the changes since [Watch.start!] or the previous [Watch.poll!] or [Watch.wait!],
+    ## without waiting. Returns an empty list if nothing changed.
+    poll! : Watcher => Try(List(Event), [WatchErr(IOErr)])
+
+    ## Waits until something changes, then keeps collecting changes until nothing has
+    ## changed for `debounce_ms` milliseconds, and returns all of them.
+    ##
+    ## Saving a file in an editor often causes several events in quick succession, so a
+    ## debounce of around 100 milliseconds avoids rebuilding several times for one save.
+    ## Repeated `Modified` events for the same path are merged into one. If changes keep
+    ## coming, it returns after ten debounce windows anyway.
+    ##
+    ## Fails with `Interrupted` if a signal caught by [Signal.listen!] arrives before
+    ## anything changed, so a program can stop waiting on Ctrl-C. If changes arrived
+    ## already, they are returned right away instead.
+    wait! : Watcher, U64 => Try(List(Event), [WatchErr(IOErr)])
+
+    ## Stops watching. Using the watcher afterwards fails.
+    stop! : Watcher => {}
+
+    ## Calls the callback with each batch of changes returned by [Watch.wait!],
+    ## until it returns `Stop` or waiting fails, for example with `Interrupted` on Ctrl-C.
+    ##
+    ## ```roc
+    ## watcher = Watch.start!(Path.from_str("src"), { recursive: Bool.True })?
+    ## Watch.on_change!(watcher, 100, |events| {
+    ##     Stdout.line!("${Str.inspect(List.len(events))} changes, rebuilding...")
+    ##     Continue
+    ## })?
+    ## ```
+    on_change! : Watcher, U64, (List(Event) => [Continue, Stop]) => Try({}, [WatchErr(IOErr)])
+    on_change! = |watcher, debounce_ms, callback!| {
+        events = wait!(watcher, debounce_ms)?
+        match callback!(events) {
+            Continue => on_change!(watcher, debounce_ms, callback!)
+            Stop => Ok({})
+        }
+    }
+}
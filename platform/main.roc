@@ -1,6 +1,6 @@
 platform ""
     requires {} { main! : List(Str) => Try({}, [Exit(I32), ..]) }
-    exposes [Cmd, Dir, Env, File, Path, Random, Sleep, Stdin, Stdout, Stderr, Utc]
+    exposes [Cmd, Dir, Env, File, Path, Process, Random, Signal, Sleep, Stdin, Stdout, Stderr, Utc, Watch]
     packages {}
     provides { main_for_host! : "main_for_host" }
     targets: {
@@ -18,12 +18,15 @@ import Dir
 import Env
 import File
 import Path
+import Process
 import Random
+import Signal
 import Sleep
 import Stdin
 import Stdout
 import Stderr
 import Utc
+import Watch
 
 main_for_host! : List(Str) => I32
 main_for_host! = |args|
@@ -1,13 +1,18 @@
 //! Roc platform host implementation for basic-cli using the new RocOps-based ABI.
 
-use std::ffi::{c_char, c_void};
+use std::ffi::{c_char, c_void, OsStr};
 use std::fs;
-use std::io::{self, BufRead, Write};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::io::{self, BufRead, Read, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::PermissionsExt;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use roc_std_new::{
     HostedFn, HostedFunctions, RocAlloc, RocCrashed, RocDbg, RocDealloc, RocExpectFailed,
-    RocList, RocOps, RocRealloc, RocStr, RocTry,
+    roc_refcounted_noop_impl, RocList, RocOps, RocRealloc, RocRefcounted, RocStr, RocTry,
 };
 
 /// Wrapper for single-variant tag unions like [PathErr(IOErr)].
@@ -320,8 +325,36 @@ extern "C" fn hosted_cmd_exec_output(
     }
 }
 
+/// Hosted function: Dir.copy_all!
+/// Takes (Path, Path, { skip_unchanged : Bool }), returns Try({ copied : U64, skipped : U64 }, [DirErr(IOErr)])
+extern "C" fn hosted_dir_copy_all(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = &*(args_ptr as *const DirCopyAllArgs);
+        roc_dir::copy_all(
+            path_from_roc_path(&args.from),
+            path_from_roc_path(&args.to),
+            args.options.skip_unchanged,
+        )
+    };
+    let try_result: TryCopyStatsDirErr = match result {
+        Ok(stats) => RocTry::ok(stats),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryCopyStatsDirErr, try_result);
+    }
+}
+
 /// Hosted function: Dir.create! (index 2)
-/// Takes Str, returns Try({}, [DirErr(IOErr)])
+/// Takes Path, returns Try({}, [DirErr(IOErr)])
 extern "C" fn hosted_dir_create(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
@@ -329,8 +362,8 @@ extern "C" fn hosted_dir_create(
 ) {
     let roc_ops = unsafe { &*ops };
     let result = unsafe {
-        let path = args_ptr as *const RocStr;
-        fs::create_dir((*path).as_str())
+        let path = args_ptr as *const RocList<u8>;
+        fs::create_dir(path_from_roc_path(&*path))
     };
     let try_result: TryUnitDirErr = match result {
         Ok(()) => RocTry::ok(()),
@@ -344,8 +377,29 @@ extern "C" fn hosted_dir_create(
     }
 }
 
+/// Hosted function: Dir.create_temp!
+/// Takes Str (prefix), returns Try(Path, [DirErr(IOErr)])
+extern "C" fn hosted_dir_create_temp(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    use std::os::unix::fs::DirBuilderExt;
+
+    let roc_ops = unsafe { &*ops };
+    let prefix = unsafe { (*(args_ptr as *const RocStr)).as_str() };
+    let result = create_temp_path(
+        prefix,
+        |path| fs::DirBuilder::new().mode(0o700).create(path),
+        roc_ops,
+    );
+    unsafe {
+        write_try_temp_path_result(ret_ptr, result, roc_ops);
+    }
+}
+
 /// Hosted function: Dir.create_all! (index 1)
-/// Takes Str, returns Try({}, [DirErr(IOErr)])
+/// Takes Path, returns Try({}, [DirErr(IOErr)])
 extern "C" fn hosted_dir_create_all(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
@@ -353,8 +407,8 @@ extern "C" fn hosted_dir_create_all(
 ) {
     let roc_ops = unsafe { &*ops };
     let result = unsafe {
-        let path = args_ptr as *const RocStr;
-        fs::create_dir_all((*path).as_str())
+        let path = args_ptr as *const RocList<u8>;
+        fs::create_dir_all(path_from_roc_path(&*path))
     };
     let try_result: TryUnitDirErr = match result {
         Ok(()) => RocTry::ok(()),
@@ -369,7 +423,7 @@ extern "C" fn hosted_dir_create_all(
 }
 
 /// Hosted function: Dir.delete_all! (index 2)
-/// Takes Str, returns Try({}, [DirErr(IOErr)])
+/// Takes Path, returns Try({}, [DirErr(IOErr)])
 extern "C" fn hosted_dir_delete_all(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
@@ -377,8 +431,8 @@ extern "C" fn hosted_dir_delete_all(
 ) {
     let roc_ops = unsafe { &*ops };
     let result = unsafe {
-        let path = args_ptr as *const RocStr;
-        fs::remove_dir_all((*path).as_str())
+        let path = args_ptr as *const RocList<u8>;
+        fs::remove_dir_all(path_from_roc_path(&*path))
     };
     let try_result: TryUnitDirErr = match result {
         Ok(()) => RocTry::ok(()),
@@ -393,7 +447,7 @@ extern "C" fn hosted_dir_delete_all(
 }
 
 /// Hosted function: Dir.delete_empty! (index 3)
-/// Takes Str, returns Try({}, [DirErr(IOErr)])
+/// Takes Path, returns Try({}, [DirErr(IOErr)])
 extern "C" fn hosted_dir_delete_empty(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
@@ -401,8 +455,8 @@ extern "C" fn hosted_dir_delete_empty(
 ) {
     let roc_ops = unsafe { &*ops };
     let result = unsafe {
-        let path = args_ptr as *const RocStr;
-        fs::remove_dir((*path).as_str())
+        let path = args_ptr as *const RocList<u8>;
+        fs::remove_dir(path_from_roc_path(&*path))
     };
     let try_result: TryUnitDirErr = match result {
         Ok(()) => RocTry::ok(()),
@@ -417,30 +471,24 @@ extern "C" fn hosted_dir_delete_empty(
 }
 
 /// Hosted function: Dir.list! (index 4)
-/// Takes Str, returns Try(List(Str), [DirErr(IOErr)])
+/// Takes Path, returns Try(List(Path), [DirErr(IOErr)])
 extern "C" fn hosted_dir_list(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
     args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let path = unsafe {
-        let args = args_ptr as *const RocStr;
-        (*args).as_str().to_string()
-    };
-
-    let result = fs::read_dir(&path);
-    let try_result: TryListStrDirErr = match result {
-        Ok(rd) => {
-            let entries: Vec<String> = rd
-                .filter_map(|entry| {
-                    entry.ok().map(|e| e.path().to_string_lossy().into_owned())
-                })
-                .collect();
-            let mut list = RocList::with_capacity(entries.len(), roc_ops);
-            for entry in entries {
-                let roc_str = RocStr::from_str(&entry, roc_ops);
-                list.push(roc_str, roc_ops);
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        fs::read_dir(path_from_roc_path(&*path))
+            .and_then(|rd| rd.map(|entry| entry.map(|e| e.path())).collect::<io::Result<Vec<_>>>())
+    };
+
+    let try_result: TryListPathDirErr = match result {
+        Ok(paths) => {
+            let mut list = RocList::with_capacity(paths.len(), roc_ops);
+            for path in paths {
+                list.push(os_str_to_roc_path(path.as_os_str(), roc_ops), roc_ops);
             }
             RocTry::ok(list)
         }
@@ -451,349 +499,1758 @@ extern "C" fn hosted_dir_list(
     };
 
     unsafe {
-        std::ptr::write(ret_ptr as *mut TryListStrDirErr, try_result);
-    }
-}
-
-/// Hosted function: Env.cwd! (index 5)
-/// Takes {}, returns Str
-extern "C" fn hosted_env_cwd(
-    ops: *const RocOps,
-    ret_ptr: *mut c_void,
-    _args_ptr: *mut c_void,
-) {
-    let roc_ops = unsafe { &*ops };
-    let cwd = std::env::current_dir()
-        .map(|p| p.to_string_lossy().into_owned())
-        .unwrap_or_default();
-    let roc_str = RocStr::from_str(&cwd, roc_ops);
-    unsafe {
-        *(ret_ptr as *mut RocStr) = roc_str;
-    }
-}
-
-/// Hosted function: Env.exe_path! (index 6)
-/// Takes {}, returns Str
-extern "C" fn hosted_env_exe_path(
-    ops: *const RocOps,
-    ret_ptr: *mut c_void,
-    _args_ptr: *mut c_void,
-) {
-    let roc_ops = unsafe { &*ops };
-    let exe_path = std::env::current_exe()
-        .map(|p| p.to_string_lossy().into_owned())
-        .unwrap_or_default();
-    let roc_str = RocStr::from_str(&exe_path, roc_ops);
-    unsafe {
-        *(ret_ptr as *mut RocStr) = roc_str;
-    }
-}
-
-/// Hosted function: Env.var! (index 7)
-/// Takes Str, returns Str
-extern "C" fn hosted_env_var(
-    ops: *const RocOps,
-    ret_ptr: *mut c_void,
-    args_ptr: *mut c_void,
-) {
-    let roc_ops = unsafe { &*ops };
-    let name = unsafe {
-        let args = args_ptr as *const RocStr;
-        (*args).as_str()
-    };
-    let value = std::env::var(name).unwrap_or_default();
-    let roc_str = RocStr::from_str(&value, roc_ops);
-    unsafe {
-        *(ret_ptr as *mut RocStr) = roc_str;
+        std::ptr::write(ret_ptr as *mut TryListPathDirErr, try_result);
     }
 }
 
-/// Hosted function: File.delete! (index 8)
-/// Takes Str (path), returns Try({}, [FileErr(IOErr)])
-extern "C" fn hosted_file_delete(
+/// Hosted function: Dir.disk_usage!
+/// Takes Path, returns Try({ bytes : U64, files : U64 }, [DirErr(IOErr)])
+extern "C" fn hosted_dir_disk_usage(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
     args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let path = unsafe {
-        let args = args_ptr as *const RocStr;
-        (*args).as_str()
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        roc_dir::disk_usage(path_from_roc_path(&*path))
     };
-    let result = fs::remove_file(path);
-    let try_result: TryUnitFileErr = match result {
-        Ok(()) => RocTry::ok(()),
+    let try_result: TryDiskUsageDirErr = match result {
+        Ok(usage) => RocTry::ok(usage),
         Err(e) => {
             let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
             RocTry::err(RocSingleTagWrapper::new(io_err))
         }
     };
     unsafe {
-        std::ptr::write(ret_ptr as *mut TryUnitFileErr, try_result);
+        std::ptr::write(ret_ptr as *mut TryDiskUsageDirErr, try_result);
     }
 }
 
-/// Hosted function: File.read_bytes! (index 9)
-/// Takes Str (path), returns Try(List(U8), [FileErr(IOErr)])
-extern "C" fn hosted_file_read_bytes(
+/// Hosted function: Dir.entries!
+/// Takes Path, returns Try(List(Entry), [DirErr(IOErr)])
+extern "C" fn hosted_dir_entries(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
     args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let path = unsafe {
-        let args = args_ptr as *const RocStr;
-        (*args).as_str()
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        roc_dir::entries(path_from_roc_path(&*path))
     };
-    let result = fs::read(path);
-    let try_result: TryBytesFileErr = match result {
-        Ok(bytes) => {
-            let mut list = RocList::with_capacity(bytes.len(), roc_ops);
-            for byte in bytes {
-                list.push(byte, roc_ops);
-            }
-            RocTry::ok(list)
-        }
+    let try_result: TryListEntryDirErr = match result {
+        Ok(entries) => RocTry::ok(roc_dir::dir_entries_to_roc(&entries, roc_ops)),
         Err(e) => {
             let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
             RocTry::err(RocSingleTagWrapper::new(io_err))
         }
     };
     unsafe {
-        std::ptr::write(ret_ptr as *mut TryBytesFileErr, try_result);
+        std::ptr::write(ret_ptr as *mut TryListEntryDirErr, try_result);
     }
 }
 
-/// Hosted function: File.read_utf8! (index 10)
-/// Takes Str (path), returns Try(Str, [FileErr(IOErr)])
-extern "C" fn hosted_file_read_utf8(
+/// Hosted function: Dir.walk!
+/// Takes (Path, WalkOptions), returns Try(List(WalkEntry), [DirErr(IOErr)])
+extern "C" fn hosted_dir_walk(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
     args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let path = unsafe {
-        let args = args_ptr as *const RocStr;
-        (*args).as_str()
+    let result = unsafe {
+        let args = &*(args_ptr as *const DirWalkArgs);
+        roc_dir::walk(path_from_roc_path(&args.path), &args.options)
     };
-    let result = fs::read_to_string(path);
-    let try_result: TryStrFileErr = match result {
-        Ok(content) => {
-            let roc_str = RocStr::from_str(&content, roc_ops);
-            RocTry::ok(roc_str)
-        }
+    let try_result: TryListWalkEntryDirErr = match result {
+        Ok(entries) => RocTry::ok(roc_dir::walk_entries_to_roc(&entries, roc_ops)),
         Err(e) => {
             let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
             RocTry::err(RocSingleTagWrapper::new(io_err))
         }
     };
     unsafe {
-        std::ptr::write(ret_ptr as *mut TryStrFileErr, try_result);
+        std::ptr::write(ret_ptr as *mut TryListWalkEntryDirErr, try_result);
     }
 }
 
-/// Hosted function: File.write_bytes! (index 11)
-/// Takes (Str, List(U8)), returns Try({}, [FileErr(IOErr)])
-extern "C" fn hosted_file_write_bytes(
+/// Hosted function: Dir.walk_respecting_ignores!
+/// Takes (Path, WalkOptions), returns Try(List(WalkEntry), [DirErr(IOErr)])
+extern "C" fn hosted_dir_walk_respecting_ignores(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
     args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
     let result = unsafe {
-        // Args are (Str, List(U8)) - a tuple/record
-        let args = args_ptr as *const (RocStr, RocList<u8>);
-        let (path, bytes) = &*args;
-        fs::write(path.as_str(), bytes.as_slice())
+        let args = &*(args_ptr as *const DirWalkArgs);
+        roc_dir::walk_respecting_ignores(path_from_roc_path(&args.path), &args.options)
     };
-    let try_result: TryUnitFileErr = match result {
-        Ok(()) => RocTry::ok(()),
+    let try_result: TryListWalkEntryDirErr = match result {
+        Ok(entries) => RocTry::ok(roc_dir::walk_entries_to_roc(&entries, roc_ops)),
         Err(e) => {
             let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
             RocTry::err(RocSingleTagWrapper::new(io_err))
         }
     };
     unsafe {
-        std::ptr::write(ret_ptr as *mut TryUnitFileErr, try_result);
+        std::ptr::write(ret_ptr as *mut TryListWalkEntryDirErr, try_result);
     }
 }
 
-/// Hosted function: File.write_utf8! (index 12)
-/// Takes (Str, Str), returns Try({}, [FileErr(IOErr)])
-extern "C" fn hosted_file_write_utf8(
+/// Hosted function: Env.cache_dir!
+/// Takes {}, returns Try(Path, [NotAvailable])
+extern "C" fn hosted_env_cache_dir(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
-    args_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let result = unsafe {
-        // Args are (Str, Str) - a tuple
-        let args = args_ptr as *const (RocStr, RocStr);
-        let (path, content) = &*args;
-        fs::write(path.as_str(), content.as_str())
-    };
-    let try_result: TryUnitFileErr = match result {
-        Ok(()) => RocTry::ok(()),
-        Err(e) => {
-            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
-            RocTry::err(RocSingleTagWrapper::new(io_err))
-        }
-    };
+    let dir = roc_system::xdg_dir(roc_system::XdgDir::Cache);
     unsafe {
-        std::ptr::write(ret_ptr as *mut TryUnitFileErr, try_result);
+        write_try_path_not_available_result(ret_ptr, dir, roc_ops);
     }
 }
 
-/// Type alias for the Path error type: [PathErr(IOErr)] in Roc
-type PathErr = RocSingleTagWrapper<roc_io_error::IOErr>;
-
-/// Type alias for Try(Bool, [PathErr(IOErr)]) - used by Path.is_file!, etc.
-type TryBoolPathErr = RocTry<bool, PathErr>;
-
-/// Type alias for the File error type: [FileErr(IOErr)] in Roc
-type FileErr = RocSingleTagWrapper<roc_io_error::IOErr>;
-
-/// Type alias for Try({}, [FileErr(IOErr)]) - used by File.write_*, File.delete!
-type TryUnitFileErr = RocTry<(), FileErr>;
-
-/// Type alias for Try(Str, [FileErr(IOErr)]) - used by File.read_utf8!
-type TryStrFileErr = RocTry<RocStr, FileErr>;
-
-/// Type alias for Try(List(U8), [FileErr(IOErr)]) - used by File.read_bytes!
-type TryBytesFileErr = RocTry<RocList<u8>, FileErr>;
-
-/// Type alias for the Dir error type: [DirErr(IOErr)] in Roc
-type DirErr = RocSingleTagWrapper<roc_io_error::IOErr>;
-
-/// Type alias for Try({}, [DirErr(IOErr)]) - used by Dir.create!, etc.
-type TryUnitDirErr = RocTry<(), DirErr>;
-
-/// Type alias for Try(List(Str), [DirErr(IOErr)]) - used by Dir.list!
-type TryListStrDirErr = RocTry<RocList<RocStr>, DirErr>;
-
-/// Write a Try(Bool, [PathErr(IOErr)]) result to ret_ptr using RocTry
-unsafe fn write_try_bool_result(
+/// Hosted function: Env.config_dir!
+/// Takes {}, returns Try(Path, [NotAvailable])
+extern "C" fn hosted_env_config_dir(
+    ops: *const RocOps,
     ret_ptr: *mut c_void,
-    result: std::io::Result<bool>,
-    roc_ops: &RocOps,
+    _args_ptr: *mut c_void,
 ) {
-    let try_result: TryBoolPathErr = match result {
-        Ok(value) => RocTry::ok(value),
-        Err(e) => {
-            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
-            RocTry::err(RocSingleTagWrapper::new(io_err))
-        }
-    };
-
-    std::ptr::write(ret_ptr as *mut TryBoolPathErr, try_result);
+    let roc_ops = unsafe { &*ops };
+    let dir = roc_system::xdg_dir(roc_system::XdgDir::Config);
+    unsafe {
+        write_try_path_not_available_result(ret_ptr, dir, roc_ops);
+    }
 }
 
-/// Hosted function: Path.is_dir! (index 13)
-/// Takes Str, returns Try(Bool, [PathErr(IOErr)])
-extern "C" fn hosted_path_is_dir(
+/// Hosted function: Env.current_user!
+/// Takes {}, returns Try(User, [UserNotFound])
+extern "C" fn hosted_env_current_user(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
-    args_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let result = unsafe {
-        let path = args_ptr as *const RocStr;
-        let path_str = (*path).as_str();
-        std::path::Path::new(path_str)
-            .symlink_metadata()
-            .map(|m| m.is_dir())
+    let user = roc_system::current_user();
+    let try_result: TryUserUserNotFound = match user {
+        Some(user) => RocTry::ok(user.to_roc(roc_ops)),
+        None => RocTry::err(()),
     };
-
     unsafe {
-        write_try_bool_result(ret_ptr, result, roc_ops);
+        std::ptr::write(ret_ptr as *mut TryUserUserNotFound, try_result);
     }
 }
 
-/// Hosted function: Path.is_file! (index 14)
-/// Takes Str, returns Try(Bool, [PathErr(IOErr)])
-extern "C" fn hosted_path_is_file(
+/// Hosted function: Env.cwd!
+/// Takes {}, returns Path
+extern "C" fn hosted_env_cwd(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
-    args_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let result = unsafe {
-        let path = args_ptr as *const RocStr;
-        let path_str = (*path).as_str();
-        std::path::Path::new(path_str)
-            .symlink_metadata()
-            .map(|m| m.is_file())
-    };
-
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let roc_path = os_str_to_roc_path(cwd.as_os_str(), roc_ops);
     unsafe {
-        write_try_bool_result(ret_ptr, result, roc_ops);
+        std::ptr::write(ret_ptr as *mut RocList<u8>, roc_path);
     }
 }
 
-/// Hosted function: Path.is_sym_link! (index 15)
-/// Takes Str, returns Try(Bool, [PathErr(IOErr)])
-extern "C" fn hosted_path_is_sym_link(
+/// Hosted function: Env.data_dir!
+/// Takes {}, returns Try(Path, [NotAvailable])
+extern "C" fn hosted_env_data_dir(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
-    args_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let result = unsafe {
-        let path = args_ptr as *const RocStr;
-        let path_str = (*path).as_str();
-        std::path::Path::new(path_str)
-            .symlink_metadata()
-            .map(|m| m.is_symlink())
-    };
-
+    let dir = roc_system::xdg_dir(roc_system::XdgDir::Data);
     unsafe {
-        write_try_bool_result(ret_ptr, result, roc_ops);
+        write_try_path_not_available_result(ret_ptr, dir, roc_ops);
     }
 }
 
-
-// ============================================================================
-// Random Module Types and Functions
-// ============================================================================
-
-/// Type alias for the Random error type: [RandomErr(IOErr)] in Roc
-type RandomErr = RocSingleTagWrapper<roc_io_error::IOErr>;
-
-/// Type alias for Try(U32, [RandomErr(IOErr)]) - using official RocTry
-type TryU32RandomErr = RocTry<u32, RandomErr>;
-
-/// Type alias for Try(U64, [RandomErr(IOErr)]) - using official RocTry
-type TryU64RandomErr = RocTry<u64, RandomErr>;
-
-/// Hosted function: Random.seed_u32!
-/// Takes {}, returns Try(U32, [RandomErr(IOErr)])
-extern "C" fn hosted_random_seed_u32(
+/// Hosted function: Env.exe_path! (index 6)
+/// Takes {}, returns Str
+extern "C" fn hosted_env_exe_path(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
     _args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let result = roc_random::random_u32(roc_ops);
-
-    let try_result: TryU32RandomErr = match result {
-        Ok(value) => RocTry::ok(value),
-        Err(io_err) => RocTry::err(RocSingleTagWrapper::new(io_err)),
-    };
+    let exe_path = std::env::current_exe()
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let roc_str = RocStr::from_str(&exe_path, roc_ops);
+    unsafe {
+        *(ret_ptr as *mut RocStr) = roc_str;
+    }
+}
 
+/// Hosted function: Env.gid!
+/// Takes {}, returns U32
+extern "C" fn hosted_env_gid(
+    _ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
     unsafe {
-        std::ptr::write(ret_ptr as *mut TryU32RandomErr, try_result);
+        *(ret_ptr as *mut u32) = roc_system::gid();
     }
 }
 
-/// Hosted function: Random.seed_u64!
-/// Takes {}, returns Try(U64, [RandomErr(IOErr)])
-extern "C" fn hosted_random_seed_u64(
+/// Hosted function: Env.home_dir!
+/// Takes {}, returns Try(Path, [NotAvailable])
+extern "C" fn hosted_env_home_dir(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
     _args_ptr: *mut c_void,
 ) {
     let roc_ops = unsafe { &*ops };
-    let result = roc_random::random_u64(roc_ops);
+    unsafe {
+        write_try_path_not_available_result(ret_ptr, roc_system::home_dir(), roc_ops);
+    }
+}
+
+/// Hosted function: Env.locales!
+/// Takes {}, returns List({ language : Str, region : Str, tag : Str })
+extern "C" fn hosted_env_locales(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let locales = roc_system::locales_to_roc(&roc_system::locales(), roc_ops);
+    unsafe {
+        std::ptr::write(ret_ptr as *mut RocList<roc_system::RocLocale>, locales);
+    }
+}
+
+/// Hosted function: Env.platform!
+/// Takes {}, returns { arch : [AARCH64, ARM, OTHER(Str), X64, X86], os : [LINUX, MACOS, OTHER(Str), WINDOWS] }
+extern "C" fn hosted_env_platform(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let platform = roc_system::platform(roc_ops);
+    unsafe {
+        std::ptr::write(ret_ptr as *mut roc_system::RocPlatform, platform);
+    }
+}
+
+/// Hosted function: Env.remove_var!
+/// Takes Str, returns Try({}, [EnvErr(IOErr)])
+extern "C" fn hosted_env_remove_var(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let name = unsafe { (*(args_ptr as *const RocStr)).as_str() };
+    let result = check_env_var_name(name).map(|()| std::env::remove_var(name));
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Env.runtime_dir!
+/// Takes {}, returns Try(Path, [NotAvailable])
+extern "C" fn hosted_env_runtime_dir(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let dir = roc_system::xdg_dir(roc_system::XdgDir::Runtime);
+    unsafe {
+        write_try_path_not_available_result(ret_ptr, dir, roc_ops);
+    }
+}
+
+/// Hosted function: Env.set_cwd!
+/// Takes Path, returns Try({}, [EnvErr(IOErr)])
+extern "C" fn hosted_env_set_cwd(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        std::env::set_current_dir(path_from_roc_path(&*path))
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Env.set_var!
+/// Takes (Str, Str), returns Try({}, [EnvErr(IOErr)])
+extern "C" fn hosted_env_set_var(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let (name, value) = unsafe {
+        let args = &*(args_ptr as *const (RocStr, RocStr));
+        (args.0.as_str(), args.1.as_str())
+    };
+    let result = check_env_var_name(name).and_then(|()| {
+        if value.contains('\0') {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("the value of environment variable {name} contains a NUL byte"),
+            ))
+        } else {
+            std::env::set_var(name, value);
+            Ok(())
+        }
+    });
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// std::env::set_var and remove_var panic on names the OS can't store, report an error instead.
+fn check_env_var_name(name: &str) -> io::Result<()> {
+    if name.is_empty() || name.contains(['=', '\0']) {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{name:?} is not a valid environment variable name"),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Hosted function: Env.system_info!
+/// Takes {}, returns { cpu_count : U64, hostname : Str, kernel_release : Str, os_version : Str, total_memory_bytes : U64 }
+extern "C" fn hosted_env_system_info(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let info = roc_system::system_info(roc_ops);
+    unsafe {
+        std::ptr::write(ret_ptr as *mut roc_system::RocSystemInfo, info);
+    }
+}
+
+/// Hosted function: Env.uid!
+/// Takes {}, returns U32
+extern "C" fn hosted_env_uid(
+    _ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    unsafe {
+        *(ret_ptr as *mut u32) = roc_system::uid();
+    }
+}
+
+/// Hosted function: Env.user_by_name!
+/// Takes Str, returns Try(User, [UserNotFound])
+extern "C" fn hosted_env_user_by_name(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let user = unsafe {
+        let name = args_ptr as *const RocStr;
+        roc_system::user_by_name((*name).as_str())
+    };
+    let try_result: TryUserUserNotFound = match user {
+        Some(user) => RocTry::ok(user.to_roc(roc_ops)),
+        None => RocTry::err(()),
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryUserUserNotFound, try_result);
+    }
+}
+
+/// Hosted function: Env.var! (index 7)
+/// Takes Str, returns Try(Str, [NotUnicode(List(U8)), VarNotFound])
+extern "C" fn hosted_env_var(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let name = unsafe {
+        let args = args_ptr as *const RocStr;
+        (*args).as_str()
+    };
+    let try_result: TryStrEnvVarErr = match std::env::var(name) {
+        Ok(value) => RocTry::ok(RocStr::from_str(&value, roc_ops)),
+        Err(std::env::VarError::NotUnicode(value)) => RocTry::err(EnvVarErr::not_unicode(
+            bytes_to_roc_list(value.as_bytes(), roc_ops),
+        )),
+        Err(std::env::VarError::NotPresent) => RocTry::err(EnvVarErr::var_not_found()),
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryStrEnvVarErr, try_result);
+    }
+}
+
+/// Hosted function: Env.vars!
+/// Takes {}, returns List((Str, Str))
+extern "C" fn hosted_env_vars(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let vars: Vec<_> = std::env::vars_os().collect();
+    let mut list = RocList::with_capacity(vars.len(), roc_ops);
+    for (name, value) in vars {
+        list.push(
+            EnvVarPair {
+                name: RocStr::from_str(&name.to_string_lossy(), roc_ops),
+                value: RocStr::from_str(&value.to_string_lossy(), roc_ops),
+            },
+            roc_ops,
+        );
+    }
+    unsafe {
+        std::ptr::write(ret_ptr as *mut RocList<EnvVarPair>, list);
+    }
+}
+
+/// Write a Try(Path, [NotAvailable]) result for Env.home_dir! and the XDG directories.
+unsafe fn write_try_path_not_available_result(
+    ret_ptr: *mut c_void,
+    value: Option<std::path::PathBuf>,
+    roc_ops: &RocOps,
+) {
+    let try_result: TryPathNotAvailable = match value {
+        Some(value) => RocTry::ok(os_str_to_roc_path(value.as_os_str(), roc_ops)),
+        None => RocTry::err(()),
+    };
+    std::ptr::write(ret_ptr as *mut TryPathNotAvailable, try_result);
+}
+
+/// Hosted function: File.chown!
+/// Takes (Path, U32, U32), returns Try({}, [FileErr(IOErr)])
+extern "C" fn hosted_file_chown(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = &*(args_ptr as *const FileChownArgs);
+        std::os::unix::fs::chown(path_from_roc_path(&args.path), Some(args.uid), Some(args.gid))
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: File.copy_with!
+/// Takes (Path, Path, { preserve_metadata : Bool }), returns Try({}, [FileErr(IOErr)])
+extern "C" fn hosted_file_copy_with(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = &*(args_ptr as *const FileCopyArgs);
+        roc_dir::copy_file(
+            path_from_roc_path(&args.from),
+            path_from_roc_path(&args.to),
+            args.options.preserve_metadata,
+        )
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: File.create_temp!
+/// Takes Str (prefix), returns Try(Path, [FileErr(IOErr)])
+extern "C" fn hosted_file_create_temp(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let roc_ops = unsafe { &*ops };
+    let prefix = unsafe { (*(args_ptr as *const RocStr)).as_str() };
+    let result = create_temp_path(
+        prefix,
+        |path| {
+            fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .mode(0o600)
+                .open(path)
+                .map(|_| ())
+        },
+        roc_ops,
+    );
+    unsafe {
+        write_try_temp_path_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: File.delete! (index 8)
+/// Takes Path, returns Try({}, [FileErr(IOErr)])
+extern "C" fn hosted_file_delete(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let path = unsafe {
+        let args = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*args)
+    };
+    let result = fs::remove_file(path);
+    let try_result: TryUnitFileErr = match result {
+        Ok(()) => RocTry::ok(()),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryUnitFileErr, try_result);
+    }
+}
+
+/// Hosted function: File.lock_exclusive!
+/// Takes Path, returns Try(Lock, [FileErr(IOErr)])
+extern "C" fn hosted_file_lock_exclusive(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let path = unsafe {
+        let args = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*args)
+    };
+    unsafe {
+        write_try_lock_result(ret_ptr, lock_file(path, libc::LOCK_EX), roc_ops);
+    }
+}
+
+/// Hosted function: File.lock_shared!
+/// Takes Path, returns Try(Lock, [FileErr(IOErr)])
+extern "C" fn hosted_file_lock_shared(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let path = unsafe {
+        let args = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*args)
+    };
+    unsafe {
+        write_try_lock_result(ret_ptr, lock_file(path, libc::LOCK_SH), roc_ops);
+    }
+}
+
+/// Hosted function: File.map!
+/// Takes Path, returns Try(Mapping, [FileErr(IOErr)])
+extern "C" fn hosted_file_map(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let path = unsafe {
+        let args = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*args)
+    };
+    let try_result: TryMappingFileErr = match map_file_read_only(path) {
+        Ok(mapping) => RocTry::ok(mapping),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryMappingFileErr, try_result);
+    }
+}
+
+/// Hosted function: File.read_mapping!
+/// Takes (Mapping, U64, U64), returns Try(List(U8), [FileErr(IOErr)])
+extern "C" fn hosted_file_read_mapping(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let args = unsafe { &*(args_ptr as *const FileReadMappingArgs) };
+    let try_result: TryBytesFileErr =
+        match read_mapping(args.mapping.id, args.offset, args.length, roc_ops) {
+            Ok(list) => RocTry::ok(list),
+            Err(e) => {
+                let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+                RocTry::err(RocSingleTagWrapper::new(io_err))
+            }
+        };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryBytesFileErr, try_result);
+    }
+}
+
+/// Hosted function: File.move!
+/// Takes (Path, Path), returns Try({}, [FileErr(IOErr)])
+extern "C" fn hosted_file_move(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = args_ptr as *const (RocList<u8>, RocList<u8>);
+        let (from, to) = &*args;
+        move_file(path_from_roc_path(from), path_from_roc_path(to))
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: File.read_bytes! (index 9)
+/// Takes Path, returns Try(List(U8), [FileErr(IOErr)])
+extern "C" fn hosted_file_read_bytes(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let path = unsafe {
+        let args = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*args)
+    };
+    let result = fs::read(path);
+    let try_result: TryBytesFileErr = match result {
+        Ok(bytes) => {
+            let mut list = RocList::with_capacity(bytes.len(), roc_ops);
+            for byte in bytes {
+                list.push(byte, roc_ops);
+            }
+            RocTry::ok(list)
+        }
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryBytesFileErr, try_result);
+    }
+}
+
+/// Hosted function: File.read_utf8! (index 10)
+/// Takes Path, returns Try(Str, [FileErr(IOErr)])
+extern "C" fn hosted_file_read_utf8(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let path = unsafe {
+        let args = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*args)
+    };
+    let result = fs::read_to_string(path);
+    let try_result: TryStrFileErr = match result {
+        Ok(content) => {
+            let roc_str = RocStr::from_str(&content, roc_ops);
+            RocTry::ok(roc_str)
+        }
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryStrFileErr, try_result);
+    }
+}
+
+/// Hosted function: File.set_permissions!
+/// Takes (Path, U32), returns Try({}, [FileErr(IOErr)])
+extern "C" fn hosted_file_set_permissions(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = &*(args_ptr as *const FileSetPermissionsArgs);
+        fs::set_permissions(path_from_roc_path(&args.path), fs::Permissions::from_mode(args.mode))
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: File.set_times!
+/// Takes (Path, U128, U128), returns Try({}, [FileErr(IOErr)])
+extern "C" fn hosted_file_set_times(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = &*(args_ptr as *const FileSetTimesArgs);
+        roc_dir::set_file_times(
+            path_from_roc_path(&args.path),
+            timespec_from_nanos(args.accessed),
+            timespec_from_nanos(args.modified),
+        )
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: File.try_lock!
+/// Takes Path, returns Try(Lock, [FileErr(IOErr), WouldBlock])
+extern "C" fn hosted_file_try_lock(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let path = unsafe {
+        let args = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*args)
+    };
+    unsafe {
+        write_try_lock_would_block_result(
+            ret_ptr,
+            lock_file(path, libc::LOCK_EX | libc::LOCK_NB),
+            roc_ops,
+        );
+    }
+}
+
+/// Hosted function: File.try_lock_shared!
+/// Takes Path, returns Try(Lock, [FileErr(IOErr), WouldBlock])
+extern "C" fn hosted_file_try_lock_shared(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let path = unsafe {
+        let args = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*args)
+    };
+    unsafe {
+        write_try_lock_would_block_result(
+            ret_ptr,
+            lock_file(path, libc::LOCK_SH | libc::LOCK_NB),
+            roc_ops,
+        );
+    }
+}
+
+/// Hosted function: File.unlock!
+/// Takes Lock ({ id : U64 }), returns {}
+extern "C" fn hosted_file_unlock(
+    _ops: *const RocOps,
+    _ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    use std::os::fd::AsRawFd;
+
+    let id = unsafe { *(args_ptr as *const u64) };
+    // A lock that was already released is gone from the table, so this does nothing
+    if let Some(held) = locks().lock().unwrap().remove(&id) {
+        // Closing the file releases the lock too, unlock first to be explicit
+        unsafe {
+            libc::flock(held.file.as_raw_fd(), libc::LOCK_UN);
+        }
+    }
+}
+
+/// Hosted function: File.unmap!
+/// Takes Mapping ({ id : U64, len : U64 }), returns {}
+extern "C" fn hosted_file_unmap(
+    _ops: *const RocOps,
+    _ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let mapping = unsafe { &*(args_ptr as *const FileMappingHandle) };
+    // Dropping the mapping unmaps it, a mapping that is already gone is left alone
+    mappings().lock().unwrap().remove(&mapping.id);
+}
+
+/// Hosted function: File.write_bytes! (index 11)
+/// Takes (Path, List(U8)), returns Try({}, [FileErr(IOErr)])
+extern "C" fn hosted_file_write_bytes(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        // Args are (Path, List(U8)) - a tuple/record
+        let args = args_ptr as *const (RocList<u8>, RocList<u8>);
+        let (path, bytes) = &*args;
+        fs::write(path_from_roc_path(path), bytes.as_slice())
+    };
+    let try_result: TryUnitFileErr = match result {
+        Ok(()) => RocTry::ok(()),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryUnitFileErr, try_result);
+    }
+}
+
+/// Hosted function: File.write_utf8! (index 12)
+/// Takes (Path, Str), returns Try({}, [FileErr(IOErr)])
+extern "C" fn hosted_file_write_utf8(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        // Args are (Path, Str) - a tuple
+        let args = args_ptr as *const (RocList<u8>, RocStr);
+        let (path, content) = &*args;
+        fs::write(path_from_roc_path(path), content.as_str())
+    };
+    let try_result: TryUnitFileErr = match result {
+        Ok(()) => RocTry::ok(()),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryUnitFileErr, try_result);
+    }
+}
+
+/// An element of the list returned by Env.vars!
+/// Roc type: (Str, Str), laid out like a record with fields in tuple order
+#[repr(C)]
+pub struct EnvVarPair {
+    pub name: RocStr,  // offset 0 (24 bytes)
+    pub value: RocStr, // offset 24 (24 bytes)
+}
+
+impl RocRefcounted for EnvVarPair {
+    fn inc(&mut self) {
+        self.name.inc();
+        self.value.inc();
+    }
+    fn dec(&mut self) {
+        self.name.dec();
+        self.value.dec();
+    }
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+/// Type alias for Try(Path, [NotAvailable]) - [NotAvailable] has no payload, so the error is zero-sized
+type TryPathNotAvailable = RocTry<RocList<u8>, ()>;
+
+/// Type alias for Try(User, [UserNotFound]) - used by Env.current_user! and Env.user_by_name!
+type TryUserUserNotFound = RocTry<roc_system::RocUser, ()>;
+
+/// Error type for Env.var!: [NotUnicode(List(U8)), VarNotFound]
+/// Alphabetically: NotUnicode=0, VarNotFound=1
+#[repr(C)]
+pub union EnvVarErrPayload {
+    not_unicode: core::mem::ManuallyDrop<RocList<u8>>,
+    var_not_found: (),
+}
+
+#[repr(C)]
+pub struct EnvVarErr {
+    payload: EnvVarErrPayload,
+    discriminant: u8, // NotUnicode=0, VarNotFound=1
+}
+
+impl EnvVarErr {
+    pub fn not_unicode(bytes: RocList<u8>) -> Self {
+        Self {
+            payload: EnvVarErrPayload {
+                not_unicode: core::mem::ManuallyDrop::new(bytes),
+            },
+            discriminant: 0,
+        }
+    }
+
+    pub fn var_not_found() -> Self {
+        Self {
+            payload: EnvVarErrPayload { var_not_found: () },
+            discriminant: 1,
+        }
+    }
+}
+
+/// Type alias for Try(Str, [NotUnicode(List(U8)), VarNotFound]) - used by Env.var!
+type TryStrEnvVarErr = RocTry<RocStr, EnvVarErr>;
+
+/// Type alias for the Path error type: [PathErr(IOErr)] in Roc
+type PathErr = RocSingleTagWrapper<roc_io_error::IOErr>;
+
+/// Type alias for Try(Bool, [PathErr(IOErr)]) - used by Path.is_file!, etc.
+type TryBoolPathErr = RocTry<bool, PathErr>;
+
+/// Type alias for Try(Path, [PathErr(IOErr)]) - used by Path.read_link!, Path.canonicalize!
+/// Path is the record { bytes : List(U8) }, which has the same layout as a List(U8).
+type TryPathPathErr = RocTry<RocList<u8>, PathErr>;
+
+/// Type alias for Try(FilesystemStats, [PathErr(IOErr)]) - used by Path.filesystem_stats!
+type TryFilesystemStatsPathErr = RocTry<roc_dir::FilesystemStats, PathErr>;
+
+/// Borrow the bytes of a Roc Path as a std Path.
+///
+/// Unix paths are arbitrary bytes, so filenames that are not valid UTF-8 round-trip unchanged.
+fn path_from_roc_path(bytes: &RocList<u8>) -> &std::path::Path {
+    std::path::Path::new(OsStr::from_bytes(bytes.as_slice()))
+}
+
+/// Convert an OS string (e.g. a path from the filesystem) to the bytes of a Roc Path.
+fn os_str_to_roc_path(os_str: &OsStr, roc_ops: &RocOps) -> RocList<u8> {
+    bytes_to_roc_list(os_str.as_bytes(), roc_ops)
+}
+
+/// Copy a byte slice into a new RocList<u8>.
+fn bytes_to_roc_list(bytes: &[u8], roc_ops: &RocOps) -> RocList<u8> {
+    let mut list = RocList::with_capacity(bytes.len(), roc_ops);
+    for &byte in bytes {
+        list.push(byte, roc_ops);
+    }
+    list
+}
+
+/// Type alias for the File error type: [FileErr(IOErr)] in Roc
+type FileErr = RocSingleTagWrapper<roc_io_error::IOErr>;
+
+/// Type alias for Try({}, [FileErr(IOErr)]) - used by File.write_*, File.delete!
+type TryUnitFileErr = RocTry<(), FileErr>;
+
+/// Type alias for Try(Str, [FileErr(IOErr)]) - used by File.read_utf8!
+type TryStrFileErr = RocTry<RocStr, FileErr>;
+
+/// Type alias for Try(List(U8), [FileErr(IOErr)]) - used by File.read_bytes!, File.read_mapping!
+type TryBytesFileErr = RocTry<RocList<u8>, FileErr>;
+
+/// Type alias for Try(Path, [FileErr(IOErr)]) - used by File.create_temp! and Dir.create_temp!
+type TryPathFileErr = RocTry<RocList<u8>, FileErr>;
+
+/// Type alias for Try(Lock, [FileErr(IOErr)]) - used by File.lock_exclusive!, File.lock_shared!
+/// Lock is the record { id : U64 }, which has the same layout as a U64.
+type TryLockFileErr = RocTry<u64, FileErr>;
+
+/// A Mapping in Roc: { id : U64, len : U64 }
+#[repr(C)]
+pub struct FileMappingHandle {
+    pub id: u64,  // offset 0 (8 bytes)
+    pub len: u64, // offset 8 (8 bytes)
+}
+
+roc_refcounted_noop_impl!(FileMappingHandle);
+
+/// Type alias for Try(Mapping, [FileErr(IOErr)]) - used by File.map!
+type TryMappingFileErr = RocTry<FileMappingHandle, FileErr>;
+
+/// Arguments for File.read_mapping!: (Mapping, U64, U64)
+/// Memory layout: all fields are 8-byte aligned, so: mapping, offset, length
+#[repr(C)]
+pub struct FileReadMappingArgs {
+    pub mapping: FileMappingHandle, // offset 0 (16 bytes)
+    pub offset: u64,                // offset 16 (8 bytes)
+    pub length: u64,                // offset 24 (8 bytes)
+}
+
+/// Error type for File.try_lock!: [FileErr(IOErr), WouldBlock]
+/// Alphabetically: FileErr=0, WouldBlock=1
+#[repr(C)]
+pub union FileTryLockErrPayload {
+    file_err: core::mem::ManuallyDrop<roc_io_error::IOErr>,
+    would_block: (),
+}
+
+#[repr(C)]
+pub struct FileTryLockErr {
+    payload: FileTryLockErrPayload,
+    discriminant: u8, // FileErr=0, WouldBlock=1
+}
+
+impl FileTryLockErr {
+    pub fn file_err(io_err: roc_io_error::IOErr) -> Self {
+        Self {
+            payload: FileTryLockErrPayload {
+                file_err: core::mem::ManuallyDrop::new(io_err),
+            },
+            discriminant: 0,
+        }
+    }
+
+    pub fn would_block() -> Self {
+        Self {
+            payload: FileTryLockErrPayload { would_block: () },
+            discriminant: 1,
+        }
+    }
+}
+
+/// Type alias for Try(Lock, [FileErr(IOErr), WouldBlock]) - used by File.try_lock!, File.try_lock_shared!
+type TryLockTryLockErr = RocTry<u64, FileTryLockErr>;
+
+/// Type alias for the Dir error type: [DirErr(IOErr)] in Roc
+type DirErr = RocSingleTagWrapper<roc_io_error::IOErr>;
+
+/// Type alias for Try({}, [DirErr(IOErr)]) - used by Dir.create!, etc.
+type TryUnitDirErr = RocTry<(), DirErr>;
+
+/// Type alias for Try(List(Path), [DirErr(IOErr)]) - used by Dir.list!
+/// Path is the record { bytes : List(U8) }, which has the same layout as a List(U8).
+type TryListPathDirErr = RocTry<RocList<RocList<u8>>, DirErr>;
+
+/// Options record for Dir.copy_all!: { skip_unchanged : Bool }
+#[repr(C)]
+pub struct DirCopyAllOptions {
+    pub skip_unchanged: bool,
+}
+
+/// Arguments for Dir.copy_all!: (Path, Path, { skip_unchanged : Bool })
+/// Memory layout: Path (24 bytes) > record (1 byte), so: from, to, options
+#[repr(C)]
+pub struct DirCopyAllArgs {
+    pub from: RocList<u8>,          // offset 0 (24 bytes)
+    pub to: RocList<u8>,            // offset 24 (24 bytes)
+    pub options: DirCopyAllOptions, // offset 48 (1 byte + padding)
+}
+
+/// Type alias for Try({ bytes : U64, files : U64 }, [DirErr(IOErr)]) - used by Dir.disk_usage!
+type TryDiskUsageDirErr = RocTry<roc_dir::DiskUsage, DirErr>;
+
+/// Type alias for Try({ copied : U64, skipped : U64 }, [DirErr(IOErr)]) - used by Dir.copy_all!
+type TryCopyStatsDirErr = RocTry<roc_dir::CopyStats, DirErr>;
+
+/// Type alias for Try(List(Entry), [DirErr(IOErr)]) - used by Dir.entries!
+type TryListEntryDirErr = RocTry<RocList<roc_dir::RocDirEntry>, DirErr>;
+
+/// Type alias for Try(List(WalkEntry), [DirErr(IOErr)]) - used by Dir.walk! and Dir.walk_respecting_ignores!
+type TryListWalkEntryDirErr = RocTry<RocList<roc_dir::RocWalkEntry>, DirErr>;
+
+/// Arguments for Dir.walk! and Dir.walk_respecting_ignores!: (Path, WalkOptions)
+/// Memory layout: both are 8-byte aligned, so: path, options
+#[repr(C)]
+pub struct DirWalkArgs {
+    pub path: RocList<u8>,              // offset 0 (24 bytes)
+    pub options: roc_dir::WalkOptions,  // offset 24 (16 bytes)
+}
+
+/// Arguments for File.set_permissions!: (Path, U32)
+/// Memory layout: Path (24 bytes) > U32 (4 bytes), so: path, mode
+#[repr(C)]
+pub struct FileSetPermissionsArgs {
+    pub path: RocList<u8>, // offset 0 (24 bytes)
+    pub mode: u32,         // offset 24 (4 bytes + padding)
+}
+
+/// Arguments for File.chown!: (Path, U32, U32)
+/// Memory layout: Path (24 bytes) > U32 (4 bytes), so: path, uid, gid
+#[repr(C)]
+pub struct FileChownArgs {
+    pub path: RocList<u8>, // offset 0 (24 bytes)
+    pub uid: u32,          // offset 24 (4 bytes)
+    pub gid: u32,          // offset 28 (4 bytes)
+}
+
+/// Arguments for File.set_times!: (Path, U128, U128)
+/// Memory layout: U128 (16-byte aligned) > Path, so: accessed, modified, path
+#[repr(C)]
+pub struct FileSetTimesArgs {
+    pub accessed: u128,    // offset 0 (16 bytes)
+    pub modified: u128,    // offset 16 (16 bytes)
+    pub path: RocList<u8>, // offset 32 (24 bytes + padding)
+}
+
+/// Convert nanoseconds since the Unix epoch (as returned by Utc.now!) to a timespec.
+fn timespec_from_nanos(nanos: u128) -> libc::timespec {
+    libc::timespec {
+        tv_sec: (nanos / 1_000_000_000) as libc::time_t,
+        tv_nsec: (nanos % 1_000_000_000) as _,
+    }
+}
+
+/// Options record for File.copy_with!: { preserve_metadata : Bool }
+#[repr(C)]
+pub struct FileCopyOptions {
+    pub preserve_metadata: bool,
+}
+
+/// Arguments for File.copy_with!: (Path, Path, { preserve_metadata : Bool })
+/// Memory layout: Path (24 bytes) > record (1 byte), so: from, to, options
+#[repr(C)]
+pub struct FileCopyArgs {
+    pub from: RocList<u8>,        // offset 0 (24 bytes)
+    pub to: RocList<u8>,          // offset 24 (24 bytes)
+    pub options: FileCopyOptions, // offset 48 (1 byte + padding)
+}
+
+/// A lock that is still taken, and the file it was taken on.
+struct HeldLock {
+    file: fs::File,
+    /// Device and inode of the file, to notice the program locking the same file twice.
+    file_id: (u64, u64),
+    exclusive: bool,
+}
+
+/// Locks that are still taken, by the id handed to Roc.
+///
+/// Ids are never reused, so a Lock that was already released can't reach a newer one.
+fn locks() -> &'static Mutex<HashMap<u64, HeldLock>> {
+    static LOCKS: OnceLock<Mutex<HashMap<u64, HeldLock>>> = OnceLock::new();
+    LOCKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+static NEXT_LOCK_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Open the file at `path`, creating it if needed, and take an advisory lock on it.
+///
+/// Returns the id of the lock; File.unlock! closes the file holding it.
+///
+/// Each lock uses its own file descriptor, so flock would make the program wait for (or
+/// fail with WouldBlock against) a lock it holds itself. That fails with an error instead,
+/// unless both locks are shared.
+fn lock_file(path: &std::path::Path, operation: libc::c_int) -> io::Result<u64> {
+    use std::os::fd::AsRawFd;
+    use std::os::unix::fs::MetadataExt;
+
+    let file = match fs::OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(path)
+    {
+        // flock does not care about the access mode, so read-only files can be locked too
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => fs::File::open(path)?,
+        result => result?,
+    };
+
+    let metadata = file.metadata()?;
+    let file_id = (metadata.dev(), metadata.ino());
+    let exclusive = operation & libc::LOCK_EX != 0;
+    let already_locked = locks()
+        .lock()
+        .unwrap()
+        .values()
+        .any(|held| held.file_id == file_id && (held.exclusive || exclusive));
+    if already_locked {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            "this program already holds a lock on this file",
+        ));
+    }
+
+    loop {
+        if unsafe { libc::flock(file.as_raw_fd(), operation) } == 0 {
+            let id = NEXT_LOCK_ID.fetch_add(1, Ordering::Relaxed);
+            let held = HeldLock {
+                file,
+                file_id,
+                exclusive,
+            };
+            locks().lock().unwrap().insert(id, held);
+            return Ok(id);
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+}
+
+/// Write a Try(Lock, [FileErr(IOErr)]) result to ret_ptr using RocTry
+unsafe fn write_try_lock_result(ret_ptr: *mut c_void, result: io::Result<u64>, roc_ops: &RocOps) {
+    let try_result: TryLockFileErr = match result {
+        Ok(id) => RocTry::ok(id),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+
+    std::ptr::write(ret_ptr as *mut TryLockFileErr, try_result);
+}
+
+/// Write a Try(Lock, [FileErr(IOErr), WouldBlock]) result to ret_ptr using RocTry
+unsafe fn write_try_lock_would_block_result(
+    ret_ptr: *mut c_void,
+    result: io::Result<u64>,
+    roc_ops: &RocOps,
+) {
+    let try_result: TryLockTryLockErr = match result {
+        Ok(id) => RocTry::ok(id),
+        Err(e) if e.raw_os_error() == Some(libc::EWOULDBLOCK) => {
+            RocTry::err(FileTryLockErr::would_block())
+        }
+        Err(e) => RocTry::err(FileTryLockErr::file_err(
+            roc_io_error::IOErr::from_io_error(&e, roc_ops),
+        )),
+    };
+
+    std::ptr::write(ret_ptr as *mut TryLockTryLockErr, try_result);
+}
+
+/// A file mapped read-only into memory by File.map!.
+struct FileMapping {
+    file: fs::File,
+    data: *mut c_void,
+    len: usize,
+}
+
+// The mapped memory is only read, and only while the table lock is held
+unsafe impl Send for FileMapping {}
+
+impl Drop for FileMapping {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe {
+                libc::munmap(self.data, self.len);
+            }
+        }
+    }
+}
+
+/// Mappings that are still alive, by the id handed to Roc.
+///
+/// Ids are never reused, so a Mapping that was already released can't reach a newer one.
+fn mappings() -> &'static Mutex<HashMap<u64, FileMapping>> {
+    static MAPPINGS: OnceLock<Mutex<HashMap<u64, FileMapping>>> = OnceLock::new();
+    MAPPINGS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+static NEXT_MAPPING_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Map a file read-only into memory, returning the mapping's id and length.
+fn map_file_read_only(path: &std::path::Path) -> io::Result<FileMappingHandle> {
+    use std::os::fd::AsRawFd;
+
+    let file = fs::File::open(path)?;
+    let len = file.metadata()?.len() as usize;
+
+    // mmap rejects empty mappings, an empty file simply has nothing to read
+    let data = if len == 0 {
+        std::ptr::null_mut()
+    } else {
+        let data = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if data == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        data
+    };
+
+    let id = NEXT_MAPPING_ID.fetch_add(1, Ordering::Relaxed);
+    mappings()
+        .lock()
+        .unwrap()
+        .insert(id, FileMapping { file, data, len });
+    Ok(FileMappingHandle {
+        id,
+        len: len as u64,
+    })
+}
+
+/// Copy up to `length` bytes starting at `offset` out of a mapping into a new list.
+///
+/// Reads stop at the file's current size: touching pages past the end of a file that was
+/// truncated after it was mapped would kill the process with SIGBUS.
+fn read_mapping(id: u64, offset: u64, length: u64, roc_ops: &RocOps) -> io::Result<RocList<u8>> {
+    let table = mappings().lock().unwrap();
+    let mapping = table.get(&id).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "the mapping was already released")
+    })?;
+
+    let current_len = mapping.file.metadata()?.len();
+    let end = offset
+        .saturating_add(length)
+        .min(mapping.len as u64)
+        .min(current_len);
+    if offset >= end {
+        return Ok(RocList::empty());
+    }
+
+    let bytes = unsafe {
+        std::slice::from_raw_parts(
+            (mapping.data as *const u8).add(offset as usize),
+            (end - offset) as usize,
+        )
+    };
+    Ok(bytes_to_roc_list(bytes, roc_ops))
+}
+
+/// Rename a file, falling back to copy and delete when `from` and `to` are on different filesystems.
+fn move_file(from: &std::path::Path, to: &std::path::Path) -> io::Result<()> {
+    match fs::rename(from, to) {
+        Err(e) if e.raw_os_error() == Some(libc::EXDEV) => {
+            // Copy next to the destination and rename over it, so a failed copy
+            // never touches a file that was already at `to`
+            let temp = create_sibling_temp(to)?;
+            let copied = roc_dir::copy_file(from, &temp, true).and_then(|()| fs::rename(&temp, to));
+            if let Err(copy_err) = copied {
+                let _ = fs::remove_file(&temp);
+                return Err(copy_err);
+            }
+            fs::remove_file(from)
+        }
+        result => result,
+    }
+}
+
+/// Create an empty file with an unused name in the same directory as `path`.
+fn create_sibling_temp(path: &std::path::Path) -> io::Result<std::path::PathBuf> {
+    let dir = path.parent().unwrap_or(std::path::Path::new(""));
+    let name = path.file_name().unwrap_or_default();
+    for attempt in 0..100u32 {
+        let mut temp_name = std::ffi::OsString::from(".");
+        temp_name.push(name);
+        temp_name.push(format!(".{}.{attempt}.tmp", std::process::id()));
+        let temp = dir.join(temp_name);
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temp)
+        {
+            Ok(_) => return Ok(temp),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "no unused temporary file name next to the destination",
+    ))
+}
+
+/// Create a uniquely named file or directory in the system temp directory, returning its path.
+///
+/// `create` must fail with AlreadyExists if the path is taken (like O_EXCL does),
+/// so a name is never handed out twice, even to another process. A `prefix` with a `/`
+/// or `..` in it could point outside the temp directory, so it fails with InvalidInput.
+fn create_temp_path(
+    prefix: &str,
+    create: impl Fn(&std::path::Path) -> io::Result<()>,
+    roc_ops: &RocOps,
+) -> Result<std::path::PathBuf, roc_io_error::IOErr> {
+    const ATTEMPTS: usize = 100;
+    const NAME_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+    if prefix.contains('/') || prefix.contains("..") {
+        let e = io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "temporary path prefix must not contain '/' or '..'",
+        );
+        return Err(roc_io_error::IOErr::from_io_error(&e, roc_ops));
+    }
+
+    let dir = std::env::temp_dir();
+    for _ in 0..ATTEMPTS {
+        let mut random = roc_random::random_u64(roc_ops)?;
+        let mut name = String::from(prefix);
+        for _ in 0..10 {
+            name.push(NAME_CHARS[(random % NAME_CHARS.len() as u64) as usize] as char);
+            random /= NAME_CHARS.len() as u64;
+        }
+
+        let path = dir.join(name);
+        match create(&path) {
+            Ok(()) => return Ok(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(roc_io_error::IOErr::from_io_error(&e, roc_ops)),
+        }
+    }
+
+    let e = io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "could not find an unused temporary path",
+    );
+    Err(roc_io_error::IOErr::from_io_error(&e, roc_ops))
+}
+
+/// Write a Try(Path, [FileErr(IOErr)]) result for File.create_temp! and Dir.create_temp!.
+///
+/// FileErr and DirErr share the same layout, so this works for both.
+unsafe fn write_try_temp_path_result(
+    ret_ptr: *mut c_void,
+    result: Result<std::path::PathBuf, roc_io_error::IOErr>,
+    roc_ops: &RocOps,
+) {
+    let try_result: TryPathFileErr = match result {
+        Ok(path) => RocTry::ok(os_str_to_roc_path(path.as_os_str(), roc_ops)),
+        Err(io_err) => RocTry::err(RocSingleTagWrapper::new(io_err)),
+    };
+
+    std::ptr::write(ret_ptr as *mut TryPathFileErr, try_result);
+}
+
+/// Write a Try({}, [FileErr(IOErr)]) result to ret_ptr using RocTry.
+///
+/// FileErr, DirErr, PathErr and EnvErr share the same layout, so this works for all of them.
+unsafe fn write_try_unit_result(
+    ret_ptr: *mut c_void,
+    result: std::io::Result<()>,
+    roc_ops: &RocOps,
+) {
+    let try_result: TryUnitFileErr = match result {
+        Ok(()) => RocTry::ok(()),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+
+    std::ptr::write(ret_ptr as *mut TryUnitFileErr, try_result);
+}
+
+/// Write a Try(Bool, [PathErr(IOErr)]) result to ret_ptr using RocTry
+unsafe fn write_try_bool_result(
+    ret_ptr: *mut c_void,
+    result: std::io::Result<bool>,
+    roc_ops: &RocOps,
+) {
+    let try_result: TryBoolPathErr = match result {
+        Ok(value) => RocTry::ok(value),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+
+    std::ptr::write(ret_ptr as *mut TryBoolPathErr, try_result);
+}
+
+/// Write a Try(Path, [PathErr(IOErr)]) result to ret_ptr using RocTry
+unsafe fn write_try_path_buf_result(
+    ret_ptr: *mut c_void,
+    result: std::io::Result<std::path::PathBuf>,
+    roc_ops: &RocOps,
+) {
+    let try_result: TryPathPathErr = match result {
+        Ok(path) => RocTry::ok(os_str_to_roc_path(path.as_os_str(), roc_ops)),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+
+    std::ptr::write(ret_ptr as *mut TryPathPathErr, try_result);
+}
+
+/// Hosted function: Path.canonicalize!
+/// Takes Path, returns Try(Path, [PathErr(IOErr)])
+extern "C" fn hosted_path_canonicalize(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        fs::canonicalize(path_from_roc_path(&*path))
+    };
+
+    unsafe {
+        write_try_path_buf_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Path.filesystem_stats!
+/// Takes Path, returns Try(FilesystemStats, [PathErr(IOErr)])
+extern "C" fn hosted_path_filesystem_stats(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        roc_dir::filesystem_stats(path_from_roc_path(&*path), roc_ops)
+    };
+    let try_result: TryFilesystemStatsPathErr = match result {
+        Ok(stats) => RocTry::ok(stats),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryFilesystemStatsPathErr, try_result);
+    }
+}
+
+/// Hosted function: Path.hard_link!
+/// Takes (Path, Path), returns Try({}, [PathErr(IOErr)])
+extern "C" fn hosted_path_hard_link(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = args_ptr as *const (RocList<u8>, RocList<u8>);
+        let (original, link) = &*args;
+        fs::hard_link(path_from_roc_path(original), path_from_roc_path(link))
+    };
+
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Path.is_dir! (index 13)
+/// Takes Path, returns Try(Bool, [PathErr(IOErr)])
+extern "C" fn hosted_path_is_dir(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*path)
+            .symlink_metadata()
+            .map(|m| m.is_dir())
+    };
+
+    unsafe {
+        write_try_bool_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Path.is_file! (index 14)
+/// Takes Path, returns Try(Bool, [PathErr(IOErr)])
+extern "C" fn hosted_path_is_file(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*path)
+            .symlink_metadata()
+            .map(|m| m.is_file())
+    };
+
+    unsafe {
+        write_try_bool_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Path.is_sym_link! (index 15)
+/// Takes Path, returns Try(Bool, [PathErr(IOErr)])
+extern "C" fn hosted_path_is_sym_link(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        path_from_roc_path(&*path)
+            .symlink_metadata()
+            .map(|m| m.is_symlink())
+    };
+
+    unsafe {
+        write_try_bool_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Path.read_link!
+/// Takes Path, returns Try(Path, [PathErr(IOErr)])
+extern "C" fn hosted_path_read_link(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let path = args_ptr as *const RocList<u8>;
+        fs::read_link(path_from_roc_path(&*path))
+    };
+
+    unsafe {
+        write_try_path_buf_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Path.symlink!
+/// Takes (Path, Path), returns Try({}, [PathErr(IOErr)])
+extern "C" fn hosted_path_symlink(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = args_ptr as *const (RocList<u8>, RocList<u8>);
+        let (target, link) = &*args;
+        std::os::unix::fs::symlink(path_from_roc_path(target), path_from_roc_path(link))
+    };
+
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+// ============================================================================
+// Process Module Types and Functions
+// ============================================================================
+
+/// Type alias for the Process error type: [ProcessErr(IOErr)] in Roc
+type ProcessErr = RocSingleTagWrapper<roc_io_error::IOErr>;
+
+/// Type alias for Try(ResourceUsage, [ProcessErr(IOErr)]) - used by Process.resource_usage!
+type TryResourceUsageProcessErr = RocTry<roc_system::ResourceUsage, ProcessErr>;
+
+/// Hosted function: Process.abort!
+/// Takes {}, never returns
+extern "C" fn hosted_process_abort(
+    _ops: *const RocOps,
+    _ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    std::process::abort();
+}
+
+/// Hosted function: Process.exit!
+/// Takes I32, never returns
+extern "C" fn hosted_process_exit(
+    _ops: *const RocOps,
+    _ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let code = unsafe { *(args_ptr as *const i32) };
+    // Output written with Stdout.write! and Stderr.write! may still be buffered
+    let _ = io::stdout().flush();
+    let _ = io::stderr().flush();
+    std::process::exit(code);
+}
+
+/// Hosted function: Process.parent_pid!
+/// Takes {}, returns U32
+extern "C" fn hosted_process_parent_pid(
+    _ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    unsafe {
+        *(ret_ptr as *mut u32) = libc::getppid() as u32;
+    }
+}
+
+/// Hosted function: Process.pid!
+/// Takes {}, returns U32
+extern "C" fn hosted_process_pid(
+    _ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    unsafe {
+        *(ret_ptr as *mut u32) = std::process::id();
+    }
+}
+
+/// Hosted function: Process.resource_usage!
+/// Takes {}, returns Try(ResourceUsage, [ProcessErr(IOErr)])
+extern "C" fn hosted_process_resource_usage(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let try_result: TryResourceUsageProcessErr = match roc_system::resource_usage() {
+        Ok(usage) => RocTry::ok(usage),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryResourceUsageProcessErr, try_result);
+    }
+}
+
+// ============================================================================
+// Random Module Types and Functions
+// ============================================================================
+
+/// Type alias for the Random error type: [RandomErr(IOErr)] in Roc
+type RandomErr = RocSingleTagWrapper<roc_io_error::IOErr>;
+
+/// Type alias for Try(U32, [RandomErr(IOErr)]) - using official RocTry
+type TryU32RandomErr = RocTry<u32, RandomErr>;
+
+/// Type alias for Try(U64, [RandomErr(IOErr)]) - using official RocTry
+type TryU64RandomErr = RocTry<u64, RandomErr>;
+
+/// Hosted function: Random.seed_u32!
+/// Takes {}, returns Try(U32, [RandomErr(IOErr)])
+extern "C" fn hosted_random_seed_u32(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = roc_random::random_u32(roc_ops);
+
+    let try_result: TryU32RandomErr = match result {
+        Ok(value) => RocTry::ok(value),
+        Err(io_err) => RocTry::err(RocSingleTagWrapper::new(io_err)),
+    };
+
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryU32RandomErr, try_result);
+    }
+}
+
+/// Hosted function: Random.seed_u64!
+/// Takes {}, returns Try(U64, [RandomErr(IOErr)])
+extern "C" fn hosted_random_seed_u64(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = roc_random::random_u64(roc_ops);
 
     let try_result: TryU64RandomErr = match result {
         Ok(value) => RocTry::ok(value),
@@ -813,7 +2270,12 @@ extern "C" fn hosted_sleep_millis(
     args_ptr: *mut c_void,
 ) {
     let millis = unsafe { *(args_ptr as *const u64) };
-    std::thread::sleep(std::time::Duration::from_millis(millis));
+    let request = libc::timespec {
+        tv_sec: (millis / 1000) as libc::time_t,
+        tv_nsec: ((millis % 1000) * 1_000_000) as libc::c_long,
+    };
+    // Unlike std::thread::sleep, a signal caught by Signal.listen! ends the sleep early
+    unsafe { libc::nanosleep(&request, std::ptr::null_mut()) };
 }
 
 /// Hosted function: Stderr.line!
@@ -850,24 +2312,187 @@ extern "C" fn hosted_stderr_write(
     }
 }
 
-/// Hosted function: Stdin.line! (index 18)
-/// Takes {}, returns Str
-extern "C" fn hosted_stdin_line(
+/// Hosted function: Stderr.write_bytes!
+/// Takes List(U8), returns Try({}, [StderrErr(IOErr)])
+extern "C" fn hosted_stderr_write_bytes(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let bytes = &*(args_ptr as *const RocList<u8>);
+        let mut handle = io::stderr().lock();
+        handle
+            .write_all(bytes.as_slice())
+            .and_then(|()| handle.flush())
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Type alias for Try(List(U8), [StdinErr(IOErr)]) - used by Stdin.bytes! and Stdin.read_to_end!
+type TryBytesStdinErr = RocTry<RocList<u8>, RocSingleTagWrapper<roc_io_error::IOErr>>;
+
+/// Write a Try(List(U8), [StdinErr(IOErr)]) result to ret_ptr using RocTry
+unsafe fn write_try_stdin_bytes_result(
+    ret_ptr: *mut c_void,
+    result: io::Result<&[u8]>,
+    roc_ops: &RocOps,
+) {
+    let try_result: TryBytesStdinErr = match result {
+        Ok(bytes) => {
+            let mut list = RocList::with_capacity(bytes.len(), roc_ops);
+            for &byte in bytes {
+                list.push(byte, roc_ops);
+            }
+            RocTry::ok(list)
+        }
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+
+    std::ptr::write(ret_ptr as *mut TryBytesStdinErr, try_result);
+}
+
+/// Hosted function: Stdin.bytes!
+/// Takes {}, returns Try(List(U8), [StdinErr(IOErr)])
+extern "C" fn hosted_stdin_bytes(
     ops: *const RocOps,
     ret_ptr: *mut c_void,
     _args_ptr: *mut c_void,
 ) {
-    let mut line = String::new();
-    let _ = io::stdin().lock().read_line(&mut line);
+    const BUF_SIZE: usize = 16_384; // 16 KiB = 16 * 1024 = 16,384 bytes
+    let roc_ops = unsafe { &*ops };
+    let mut buffer = [0u8; BUF_SIZE];
+    let result = io::stdin()
+        .lock()
+        .read(&mut buffer)
+        .map(|bytes_read| &buffer[..bytes_read]);
+    unsafe {
+        write_try_stdin_bytes_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Error type for Stdin.line!: [EndOfFile, StdinErr(IOErr)]
+/// Alphabetically: EndOfFile=0, StdinErr=1
+#[repr(C)]
+pub union StdinLineErrPayload {
+    end_of_file: (),
+    stdin_err: core::mem::ManuallyDrop<roc_io_error::IOErr>,
+}
+
+#[repr(C)]
+pub struct StdinLineErr {
+    payload: StdinLineErrPayload,
+    discriminant: u8, // EndOfFile=0, StdinErr=1
+}
 
-    // Create RocStr - ownership transfers to Roc
+impl StdinLineErr {
+    pub fn end_of_file() -> Self {
+        Self {
+            payload: StdinLineErrPayload { end_of_file: () },
+            discriminant: 0,
+        }
+    }
+
+    pub fn stdin_err(io_err: roc_io_error::IOErr) -> Self {
+        Self {
+            payload: StdinLineErrPayload {
+                stdin_err: core::mem::ManuallyDrop::new(io_err),
+            },
+            discriminant: 1,
+        }
+    }
+}
+
+/// Type alias for Try(Str, [EndOfFile, StdinErr(IOErr)]) - used by Stdin.line!
+type TryStrStdinLineErr = RocTry<RocStr, StdinLineErr>;
+
+/// Hosted function: Stdin.line!
+/// Takes {}, returns Try(Str, [EndOfFile, StdinErr(IOErr)])
+extern "C" fn hosted_stdin_line(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
     let roc_ops = unsafe { &*ops };
-    // Trim the trailing newline
-    let roc_str = RocStr::from_str(line.trim_end_matches('\n'), roc_ops);
+    let try_result: TryStrStdinLineErr = match read_stdin_line() {
+        // Nothing was read, not even a newline
+        Ok(line) if line.is_empty() => RocTry::err(StdinLineErr::end_of_file()),
+        Ok(line) => {
+            // Trim the line ending, which is missing if the input ended without one
+            let line = line
+                .strip_suffix('\n')
+                .map(|line| line.strip_suffix('\r').unwrap_or(line))
+                .unwrap_or(&line);
+            RocTry::ok(RocStr::from_str(line, roc_ops))
+        }
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(StdinLineErr::stdin_err(io_err))
+        }
+    };
 
     unsafe {
-        *(ret_ptr as *mut RocStr) = roc_str;
-        // DO NOT call decref - ownership transferred to Roc
+        std::ptr::write(ret_ptr as *mut TryStrStdinLineErr, try_result);
+    }
+}
+
+/// Read a line from stdin like BufRead::read_line, including its line ending.
+///
+/// read_line retries when a signal interrupts it, so a program listening for Ctrl-C would
+/// keep waiting for input. This fails with Interrupted instead, unless part of the line
+/// was already read.
+fn read_stdin_line() -> io::Result<String> {
+    let mut stdin = io::stdin().lock();
+    let mut line = Vec::new();
+    loop {
+        let available = match stdin.fill_buf() {
+            Ok(available) => available,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted && !line.is_empty() => continue,
+            Err(e) => return Err(e),
+        };
+        let (used, done) = match available.iter().position(|&byte| byte == b'\n') {
+            Some(end) => {
+                line.extend_from_slice(&available[..=end]);
+                (end + 1, true)
+            }
+            None => {
+                line.extend_from_slice(available);
+                (available.len(), available.is_empty())
+            }
+        };
+        stdin.consume(used);
+        if done {
+            return String::from_utf8(line).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                )
+            });
+        }
+    }
+}
+
+/// Hosted function: Stdin.read_to_end!
+/// Takes {}, returns Try(List(U8), [StdinErr(IOErr)])
+extern "C" fn hosted_stdin_read_to_end(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let mut buffer = Vec::new();
+    let result = io::stdin()
+        .lock()
+        .read_to_end(&mut buffer)
+        .map(|_| buffer.as_slice());
+    unsafe {
+        write_try_stdin_bytes_result(ret_ptr, result, roc_ops);
     }
 }
 
@@ -905,6 +2530,26 @@ extern "C" fn hosted_stdout_write(
     }
 }
 
+/// Hosted function: Stdout.write_bytes!
+/// Takes List(U8), returns Try({}, [StdoutErr(IOErr)])
+extern "C" fn hosted_stdout_write_bytes(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let bytes = &*(args_ptr as *const RocList<u8>);
+        let mut handle = io::stdout().lock();
+        handle
+            .write_all(bytes.as_slice())
+            .and_then(|()| handle.flush())
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
 /// Hosted function: Utc.now!
 /// Takes {}, returns U128 (nanoseconds since Unix epoch)
 extern "C" fn hosted_utc_now(
@@ -912,8 +2557,6 @@ extern "C" fn hosted_utc_now(
     ret_ptr: *mut c_void,
     _args_ptr: *mut c_void,
 ) {
-    use std::time::{SystemTime, UNIX_EPOCH};
-
     let since_epoch = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .expect("time went backwards");
@@ -924,36 +2567,312 @@ extern "C" fn hosted_utc_now(
     }
 }
 
+// ============================================================================
+// Signal Module Types and Functions
+// ============================================================================
+
+/// Type alias for the Signal error type: [SignalErr(IOErr)] in Roc
+type SignalErr = RocSingleTagWrapper<roc_io_error::IOErr>;
+
+/// Type alias for Try(List(Signal), [SignalErr(IOErr)]) - used by Signal.poll!
+/// Signal has no payloads, so each one is just its discriminant
+type TryListSignalSignalErr = RocTry<RocList<u8>, SignalErr>;
+
+/// Type alias for Try(Signal, [SignalErr(IOErr)]) - used by Signal.wait!
+type TrySignalSignalErr = RocTry<u8, SignalErr>;
+
+/// Arguments for Signal.send!
+/// Roc type: (U32, [Hangup, Interrupt, Terminate, User1])
+#[repr(C)]
+struct SignalSendArgs {
+    pid: u32,   // offset 0 (4 bytes)
+    signal: u8, // offset 4 (1 byte + padding = 8 total)
+}
+
+fn signal_from_roc(discriminant: u8) -> roc_signal::Signal {
+    roc_signal::Signal::from_discriminant(discriminant)
+        .expect("Roc passed an invalid signal discriminant")
+}
+
+/// Hosted function: Signal.listen!
+/// Takes List([Hangup, Interrupt, Terminate, User1]), returns Try({}, [SignalErr(IOErr)])
+extern "C" fn hosted_signal_listen(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let signals: Vec<roc_signal::Signal> = unsafe {
+        let list = &*(args_ptr as *const RocList<u8>);
+        list.as_slice().iter().copied().map(signal_from_roc).collect()
+    };
+    unsafe {
+        write_try_unit_result(ret_ptr, roc_signal::listen(&signals), roc_ops);
+    }
+}
+
+/// Hosted function: Signal.poll!
+/// Takes {}, returns Try(List([Hangup, Interrupt, Terminate, User1]), [SignalErr(IOErr)])
+extern "C" fn hosted_signal_poll(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let try_result: TryListSignalSignalErr = match roc_signal::poll() {
+        Ok(signals) => {
+            let mut list = RocList::with_capacity(signals.len(), roc_ops);
+            for signal in signals {
+                list.push(signal as u8, roc_ops);
+            }
+            RocTry::ok(list)
+        }
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryListSignalSignalErr, try_result);
+    }
+}
+
+/// Hosted function: Signal.send!
+/// Takes (U32, [Hangup, Interrupt, Terminate, User1]), returns Try({}, [SignalErr(IOErr)])
+extern "C" fn hosted_signal_send(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let args = unsafe { &*(args_ptr as *const SignalSendArgs) };
+    let result = roc_signal::send(args.pid, signal_from_roc(args.signal));
+    unsafe {
+        write_try_unit_result(ret_ptr, result, roc_ops);
+    }
+}
+
+/// Hosted function: Signal.wait!
+/// Takes {}, returns Try([Hangup, Interrupt, Terminate, User1], [SignalErr(IOErr)])
+extern "C" fn hosted_signal_wait(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    _args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let try_result: TrySignalSignalErr = match roc_signal::wait() {
+        Ok(signal) => RocTry::ok(signal as u8),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TrySignalSignalErr, try_result);
+    }
+}
+
+// ============================================================================
+// Watch Module Types and Functions
+// ============================================================================
+
+/// Type alias for the Watch error type: [WatchErr(IOErr)] in Roc
+type WatchErr = RocSingleTagWrapper<roc_io_error::IOErr>;
+
+/// Type alias for Try(Watcher, [WatchErr(IOErr)]) - Watcher is { id : U64 }
+type TryWatcherWatchErr = RocTry<u64, WatchErr>;
+
+/// Type alias for Try(List(Event), [WatchErr(IOErr)])
+type TryListEventWatchErr = RocTry<RocList<roc_watch::RocEvent>, WatchErr>;
+
+/// Arguments for Watch.start!: (Path, { recursive : Bool })
+/// Memory layout: Path (24 bytes) > Bool (1 byte), so: path, options
+#[repr(C)]
+pub struct WatchStartArgs {
+    pub path: RocList<u8>,                 // offset 0 (24 bytes)
+    pub options: roc_watch::WatchOptions,  // offset 24 (1 byte)
+}
+
+/// Arguments for Watch.wait!: (Watcher, U64)
+/// Memory layout: both are 8-byte aligned, so in order: watcher, debounce_ms
+#[repr(C)]
+pub struct WatchWaitArgs {
+    pub watcher: u64,     // offset 0
+    pub debounce_ms: u64, // offset 8
+}
+
+/// Write a Try(List(Event), [WatchErr(IOErr)]) result for Watch.poll! and Watch.wait!.
+unsafe fn write_try_events_result(
+    ret_ptr: *mut c_void,
+    result: io::Result<Vec<roc_watch::Event>>,
+    roc_ops: &RocOps,
+) {
+    let try_result: TryListEventWatchErr = match result {
+        Ok(events) => RocTry::ok(roc_watch::events_to_roc(&events, roc_ops)),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+
+    std::ptr::write(ret_ptr as *mut TryListEventWatchErr, try_result);
+}
+
+/// Hosted function: Watch.poll!
+/// Takes Watcher ({ id : U64 }), returns Try(List(Event), [WatchErr(IOErr)])
+extern "C" fn hosted_watch_poll(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let id = unsafe { *(args_ptr as *const u64) };
+    unsafe {
+        write_try_events_result(ret_ptr, roc_watch::poll(id), roc_ops);
+    }
+}
+
+/// Hosted function: Watch.start!
+/// Takes (Path, { recursive : Bool }), returns Try(Watcher, [WatchErr(IOErr)])
+extern "C" fn hosted_watch_start(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let result = unsafe {
+        let args = &*(args_ptr as *const WatchStartArgs);
+        roc_watch::start(
+            path_from_roc_path(&args.path),
+            args.options.recursive,
+        )
+    };
+    let try_result: TryWatcherWatchErr = match result {
+        Ok(id) => RocTry::ok(id),
+        Err(e) => {
+            let io_err = roc_io_error::IOErr::from_io_error(&e, roc_ops);
+            RocTry::err(RocSingleTagWrapper::new(io_err))
+        }
+    };
+    unsafe {
+        std::ptr::write(ret_ptr as *mut TryWatcherWatchErr, try_result);
+    }
+}
+
+/// Hosted function: Watch.stop!
+/// Takes Watcher ({ id : U64 }), returns {}
+extern "C" fn hosted_watch_stop(
+    _ops: *const RocOps,
+    _ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let id = unsafe { *(args_ptr as *const u64) };
+    roc_watch::stop(id);
+}
+
+/// Hosted function: Watch.wait!
+/// Takes (Watcher, U64), returns Try(List(Event), [WatchErr(IOErr)])
+extern "C" fn hosted_watch_wait(
+    ops: *const RocOps,
+    ret_ptr: *mut c_void,
+    args_ptr: *mut c_void,
+) {
+    let roc_ops = unsafe { &*ops };
+    let args = unsafe { &*(args_ptr as *const WatchWaitArgs) };
+    let result = roc_watch::wait(args.watcher, Duration::from_millis(args.debounce_ms));
+    unsafe {
+        write_try_events_result(ret_ptr, result, roc_ops);
+    }
+}
+
 /// Array of hosted function pointers, sorted alphabetically by fully-qualified name.
 /// IMPORTANT: Order must match the order Roc expects based on alphabetical sorting.
-static HOSTED_FNS: [HostedFn; 27] = [
-    hosted_cmd_exec_exit_code, // 0:  Cmd.exec_exit_code!
-    hosted_cmd_exec_output,    // 1:  Cmd.exec_output!
-    hosted_dir_create,         // 2:  Dir.create!
-    hosted_dir_create_all,     // 3:  Dir.create_all!
-    hosted_dir_delete_all,     // 4:  Dir.delete_all!
-    hosted_dir_delete_empty,   // 5:  Dir.delete_empty!
-    hosted_dir_list,           // 6:  Dir.list!
-    hosted_env_cwd,            // 7:  Env.cwd!
-    hosted_env_exe_path,       // 8:  Env.exe_path!
-    hosted_env_var,            // 9:  Env.var!
-    hosted_file_delete,        // 10: File.delete!
-    hosted_file_read_bytes,    // 11: File.read_bytes!
-    hosted_file_read_utf8,     // 12: File.read_utf8!
-    hosted_file_write_bytes,   // 13: File.write_bytes!
-    hosted_file_write_utf8,    // 14: File.write_utf8!
-    hosted_path_is_dir,        // 15: Path.is_dir!
-    hosted_path_is_file,       // 16: Path.is_file!
-    hosted_path_is_sym_link,   // 17: Path.is_sym_link!
-    hosted_random_seed_u32,    // 18: Random.seed_u32!
-    hosted_random_seed_u64,    // 19: Random.seed_u64!
-    hosted_sleep_millis,       // 21: Sleep.millis!
-    hosted_stderr_line,        // 22: Stderr.line!
-    hosted_stderr_write,       // 23: Stderr.write!
-    hosted_stdin_line,         // 24: Stdin.line!
-    hosted_stdout_line,        // 25: Stdout.line!
-    hosted_stdout_write,       // 26: Stdout.write!
-    hosted_utc_now,            // 27: Utc.now!
+static HOSTED_FNS: [HostedFn; 85] = [
+    hosted_cmd_exec_exit_code,          // 0:  Cmd.exec_exit_code!
+    hosted_cmd_exec_output,             // 1:  Cmd.exec_output!
+    hosted_dir_copy_all,                // 2:  Dir.copy_all!
+    hosted_dir_create,                  // 3:  Dir.create!
+    hosted_dir_create_all,              // 4:  Dir.create_all!
+    hosted_dir_create_temp,             // 5:  Dir.create_temp!
+    hosted_dir_delete_all,              // 6:  Dir.delete_all!
+    hosted_dir_delete_empty,            // 7:  Dir.delete_empty!
+    hosted_dir_disk_usage,              // 8:  Dir.disk_usage!
+    hosted_dir_entries,                 // 9:  Dir.entries!
+    hosted_dir_list,                    // 10: Dir.list!
+    hosted_dir_walk,                    // 11: Dir.walk!
+    hosted_dir_walk_respecting_ignores, // 12: Dir.walk_respecting_ignores!
+    hosted_env_cache_dir,               // 13: Env.cache_dir!
+    hosted_env_config_dir,              // 14: Env.config_dir!
+    hosted_env_current_user,            // 15: Env.current_user!
+    hosted_env_cwd,                     // 16: Env.cwd!
+    hosted_env_data_dir,                // 17: Env.data_dir!
+    hosted_env_exe_path,                // 18: Env.exe_path!
+    hosted_env_gid,                     // 19: Env.gid!
+    hosted_env_home_dir,                // 20: Env.home_dir!
+    hosted_env_locales,                 // 21: Env.locales!
+    hosted_env_platform,                // 22: Env.platform!
+    hosted_env_remove_var,              // 23: Env.remove_var!
+    hosted_env_runtime_dir,             // 24: Env.runtime_dir!
+    hosted_env_set_cwd,                 // 25: Env.set_cwd!
+    hosted_env_set_var,                 // 26: Env.set_var!
+    hosted_env_system_info,             // 27: Env.system_info!
+    hosted_env_uid,                     // 28: Env.uid!
+    hosted_env_user_by_name,            // 29: Env.user_by_name!
+    hosted_env_var,                     // 30: Env.var!
+    hosted_env_vars,                    // 31: Env.vars!
+    hosted_file_chown,                  // 32: File.chown!
+    hosted_file_copy_with,              // 33: File.copy_with!
+    hosted_file_create_temp,            // 34: File.create_temp!
+    hosted_file_delete,                 // 35: File.delete!
+    hosted_file_lock_exclusive,         // 36: File.lock_exclusive!
+    hosted_file_lock_shared,            // 37: File.lock_shared!
+    hosted_file_map,                    // 38: File.map!
+    hosted_file_move,                   // 39: File.move!
+    hosted_file_read_bytes,             // 40: File.read_bytes!
+    hosted_file_read_mapping,           // 41: File.read_mapping!
+    hosted_file_read_utf8,              // 42: File.read_utf8!
+    hosted_file_set_permissions,        // 43: File.set_permissions!
+    hosted_file_set_times,              // 44: File.set_times!
+    hosted_file_try_lock,               // 45: File.try_lock!
+    hosted_file_try_lock_shared,        // 46: File.try_lock_shared!
+    hosted_file_unlock,                 // 47: File.unlock!
+    hosted_file_unmap,                  // 48: File.unmap!
+    hosted_file_write_bytes,            // 49: File.write_bytes!
+    hosted_file_write_utf8,             // 50: File.write_utf8!
+    hosted_path_canonicalize,           // 51: Path.canonicalize!
+    hosted_path_filesystem_stats,       // 52: Path.filesystem_stats!
+    hosted_path_hard_link,              // 53: Path.hard_link!
+    hosted_path_is_dir,                 // 54: Path.is_dir!
+    hosted_path_is_file,                // 55: Path.is_file!
+    hosted_path_is_sym_link,            // 56: Path.is_sym_link!
+    hosted_path_read_link,              // 57: Path.read_link!
+    hosted_path_symlink,                // 58: Path.symlink!
+    hosted_process_abort,               // 59: Process.abort!
+    hosted_process_exit,                // 60: Process.exit!
+    hosted_process_parent_pid,          // 61: Process.parent_pid!
+    hosted_process_pid,                 // 62: Process.pid!
+    hosted_process_resource_usage,      // 63: Process.resource_usage!
+    hosted_random_seed_u32,             // 64: Random.seed_u32!
+    hosted_random_seed_u64,             // 65: Random.seed_u64!
+    hosted_signal_listen,               // 66: Signal.listen!
+    hosted_signal_poll,                 // 67: Signal.poll!
+    hosted_signal_send,                 // 68: Signal.send!
+    hosted_signal_wait,                 // 69: Signal.wait!
+    hosted_sleep_millis,                // 70: Sleep.millis!
+    hosted_stderr_line,                 // 71: Stderr.line!
+    hosted_stderr_write,                // 72: Stderr.write!
+    hosted_stderr_write_bytes,          // 73: Stderr.write_bytes!
+    hosted_stdin_bytes,                 // 74: Stdin.bytes!
+    hosted_stdin_line,                  // 75: Stdin.line!
+    hosted_stdin_read_to_end,           // 76: Stdin.read_to_end!
+    hosted_stdout_line,                 // 77: Stdout.line!
+    hosted_stdout_write,                // 78: Stdout.write!
+    hosted_stdout_write_bytes,          // 79: Stdout.write_bytes!
+    hosted_utc_now,                     // 80: Utc.now!
+    hosted_watch_poll,                  // 81: Watch.poll!
+    hosted_watch_start,                 // 82: Watch.start!
+    hosted_watch_stop,                  // 83: Watch.stop!
+    hosted_watch_wait,                  // 84: Watch.wait!
 ];
 
 /// Build a RocList<RocStr> from command-line arguments.